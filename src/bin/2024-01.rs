@@ -1,6 +1,6 @@
 use std::{iter::zip, ops::Mul};
 
-advent_of_code::solution!(1);
+advent_of_code::solution!(1, year = 2024);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let mut left = Vec::<u32>::new();
@@ -57,13 +57,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(11));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(31));
     }
 }
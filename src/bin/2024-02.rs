@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-advent_of_code::solution!(2);
+advent_of_code::solution!(2, year = 2024);
 
 #[derive(Debug, PartialEq)]
 enum DiffType {
@@ -122,13 +122,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(4));
     }
 }
@@ -1,6 +1,6 @@
 use regex::Regex;
 
-advent_of_code::solution!(3);
+advent_of_code::solution!(3, year = 2024);
 
 pub fn part_one(input: &str) -> Option<u32> {
     let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
@@ -53,7 +53,9 @@ mod tests {
     #[test]
     fn test_part_one() {
         let result = part_one(&advent_of_code::template::read_file_part(
-            "examples", DAY, 1,
+            "examples",
+            PUZZLE,
+            advent_of_code::part!(1),
         ));
         assert_eq!(result, Some(161));
     }
@@ -61,7 +63,9 @@ mod tests {
     #[test]
     fn test_part_two() {
         let result = part_two(&advent_of_code::template::read_file_part(
-            "examples", DAY, 2,
+            "examples",
+            PUZZLE,
+            advent_of_code::part!(2),
         ));
         assert_eq!(result, Some(48));
     }
@@ -1,4 +1,4 @@
-advent_of_code::solution!(4);
+advent_of_code::solution!(4, year = 2024);
 
 fn transpose(matrix: Vec<Vec<char>>) -> Vec<Vec<char>> {
     let length = matrix[0].len();
@@ -139,13 +139,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(9));
     }
 }
@@ -1,6 +1,6 @@
 use std::{cmp::Ordering, collections::HashMap};
 
-advent_of_code::solution!(5);
+advent_of_code::solution!(5, year = 2024);
 
 fn compare(order: HashMap<u32, Vec<u32>>, a: u32, b: u32) -> Option<bool> {
     if let Some(values) = order.get(&a) {
@@ -100,13 +100,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(143));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(123));
     }
 }
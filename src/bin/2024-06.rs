@@ -1,6 +1,6 @@
 use std::{any::Any, collections::HashSet, fmt::Debug, ops::Add};
 
-advent_of_code::solution!(6);
+advent_of_code::solution!(6, year = 2024);
 
 #[derive(Hash, Debug, Clone, PartialEq, Eq)]
 enum Direction {
@@ -332,13 +332,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(41));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(6));
     }
 }
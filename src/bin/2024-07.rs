@@ -1,4 +1,4 @@
-advent_of_code::solution!(7);
+advent_of_code::solution!(7, year = 2024);
 
 fn join_operator(lhs: u64, rhs: u64) -> u64 {
     let mut append_result = String::new();
@@ -120,13 +120,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(3749));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(11387));
     }
 }
@@ -1,6 +1,6 @@
 use std::iter::repeat;
 
-advent_of_code::solution!(9);
+advent_of_code::solution!(9, year = 2024);
 
 #[derive(Debug, Clone, Copy)]
 enum DiskEntry {
@@ -67,13 +67,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(1928));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, None);
     }
 }
@@ -1,81 +1,182 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, check_day, download, examples, extract_examples, leaderboard, read, scaffold, solve,
+    stats, time, unscaffold,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{Day, DayBeyondEventError, Part, PuzzleId, Year};
     use std::process;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
+            part: Option<Part>,
+        },
+        ExtractExamples {
+            puzzle: PuzzleId,
+            overwrite: bool,
+        },
+        Examples {
+            puzzle: PuzzleId,
+        },
+        Unscaffold {
+            puzzle: PuzzleId,
+            yes: bool,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
+            bench: bool,
+            template: Option<String>,
+            answer_type: Option<String>,
+        },
+        CheckDay {
+            year: Year,
+            day: Option<Day>,
+            release: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             dhat: bool,
-            submit: Option<u8>,
+            submit: Option<Part>,
+            wait: bool,
+            watch: bool,
         },
         All {
+            year: Year,
             release: bool,
         },
+        Leaderboard {
+            year: Year,
+            id: Option<String>,
+            file: Option<String>,
+            day: Option<Day>,
+        },
+        Stats {
+            year: Year,
+        },
         Time {
+            year: Year,
             all: bool,
             day: Option<Day>,
             store: bool,
         },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
+    }
+
+    /// Rejects an optional day that is beyond the end of the event of the year.
+    fn event_day(year: Year, day: Option<Day>) -> Result<Option<Day>, DayBeyondEventError> {
+        day.map(|day| PuzzleId::try_new(year, day).map(|puzzle| puzzle.day))
+            .transpose()
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
-        let app_args = match args.subcommand()?.as_deref() {
+        let subcommand = args.subcommand()?;
+        // NOTE: options have to be parsed before the free day argument.
+        let year = args
+            .opt_value_from_str("--year")?
+            .unwrap_or_else(Year::configured);
+
+        let app_args = match subcommand.as_deref() {
             Some("all") => AppArguments::All {
+                year,
                 release: args.contains("--release"),
             },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                year,
+                id: args.opt_value_from_str("--id")?,
+                file: args.opt_value_from_str("--file")?,
+                day: event_day(year, args.opt_value_from_str("--day")?)?,
+            },
+            Some("stats") => AppArguments::Stats { year },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    day: event_day(year, args.opt_free_from_str()?)?,
                     store,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
-            },
-            Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
             },
-            Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
-                download: args.contains("--download"),
+            Some("read") => {
+                let part = args.opt_value_from_str("--part")?;
+                AppArguments::Read {
+                    puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
+                    part,
+                }
+            }
+            Some("extract-examples") => AppArguments::ExtractExamples {
+                puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
                 overwrite: args.contains("--overwrite"),
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
+            Some("examples") => AppArguments::Examples {
+                puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
             },
+            Some("scaffold") => {
+                let template = args.opt_value_from_str("--template")?;
+                let answer_type = args.opt_value_from_str("--answer-type")?;
+                AppArguments::Scaffold {
+                    puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
+                    download: args.contains("--download"),
+                    overwrite: args.contains("--overwrite"),
+                    bench: args.contains("--bench"),
+                    template,
+                    answer_type,
+                }
+            }
+            Some("unscaffold") => {
+                let yes = args.contains("--yes");
+                AppArguments::Unscaffold {
+                    puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
+                    yes,
+                }
+            }
+            Some("check-day") => {
+                let all = args.contains("--all");
+                let release = args.contains("--release");
+                let day = event_day(year, args.opt_free_from_str()?)?;
+
+                if day.is_none() && !all {
+                    eprintln!("Pass a day or `--all`. Format: cargo check-day 1");
+                    process::exit(1);
+                }
+
+                AppArguments::CheckDay { year, day, release }
+            }
+            Some("solve") => {
+                let submit = args.opt_value_from_str("--submit")?;
+                AppArguments::Solve {
+                    puzzle: PuzzleId::try_new(year, args.free_from_str()?)?,
+                    release: args.contains("--release"),
+                    submit,
+                    dhat: args.contains("--dhat"),
+                    wait: args.contains("--wait"),
+                    watch: args.contains("--watch"),
+                }
+            }
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -102,43 +203,53 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold {
+            AppArguments::All { year, release } => all::handle(year, release),
+            AppArguments::Leaderboard {
+                year,
+                id,
+                file,
                 day,
+            } => leaderboard::handle(year, id, file, day),
+            AppArguments::Stats { year } => stats::handle(year),
+            AppArguments::Time {
+                year,
+                day,
+                all,
+                store,
+            } => time::handle(year, day, all, store),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle, part } => read::handle(puzzle, part),
+            AppArguments::ExtractExamples { puzzle, overwrite } => {
+                extract_examples::handle(puzzle, overwrite);
+            }
+            AppArguments::Examples { puzzle } => examples::handle(puzzle),
+            AppArguments::Unscaffold { puzzle, yes } => unscaffold::handle(puzzle, yes),
+            AppArguments::Scaffold {
+                puzzle,
                 download,
                 overwrite,
+                bench,
+                template,
+                answer_type,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite, bench, template.as_deref(), answer_type);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
             }
+            AppArguments::CheckDay { year, day, release } => {
+                check_day::handle(year, day, release);
+            }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                wait,
+                watch,
+            } => solve::handle(puzzle, release, dhat, submit, wait, watch),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
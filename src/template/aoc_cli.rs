@@ -1,16 +1,21 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
     fmt::Display,
+    fs,
     process::{Command, Output, Stdio},
 };
 
-use crate::template::Day;
+use crate::template::inputs::{self, InputError};
+use crate::template::{paths, Part, PuzzleId, Verdict};
 
 #[derive(Debug)]
 pub enum AocCommandError {
     CommandNotFound,
     CommandNotCallable,
     BadExitStatus(Output),
+    UnknownResponse(Output),
+    InvalidInput(InputError),
+    IO(std::io::Error),
 }
 
 impl Display for AocCommandError {
@@ -21,6 +26,11 @@ impl Display for AocCommandError {
             AocCommandError::BadExitStatus(_) => {
                 write!(f, "aoc-cli exited with a non-zero status.")
             }
+            AocCommandError::UnknownResponse(_) => {
+                write!(f, "aoc-cli printed a response that could not be parsed.")
+            }
+            AocCommandError::InvalidInput(e) => write!(f, "invalid input: {e}"),
+            AocCommandError::IO(e) => write!(f, "could not write input file: {e}"),
         }
     }
 }
@@ -33,8 +43,8 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
-    let puzzle_path = get_puzzle_path(day);
+pub fn read(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let puzzle_path = paths::puzzle(puzzle);
 
     let args = build_args(
         "read",
@@ -43,76 +53,89 @@ pub fn read(day: Day) -> Result<Output, AocCommandError> {
             "--puzzle-file".into(),
             puzzle_path,
         ],
-        day,
+        puzzle,
     );
 
-    call_aoc_cli(&args)
+    call_aoc_cli(&args, false)
 }
 
-pub fn download(day: Day) -> Result<Output, AocCommandError> {
-    let input_path = get_input_path(day);
-    let puzzle_path = get_puzzle_path(day);
+/// Downloads input and puzzle description of a day.
+/// The input is downloaded to a temporary file first and only replaces the existing input if it is valid.
+pub fn download(puzzle: PuzzleId) -> Result<Output, AocCommandError> {
+    let input_path = paths::input(puzzle);
+    let download_path = format!("{input_path}.download");
+    let puzzle_path = paths::puzzle(puzzle);
 
     let args = build_args(
         "download",
         &[
             "--overwrite".into(),
             "--input-file".into(),
-            input_path.to_string(),
+            download_path.to_string(),
             "--puzzle-file".into(),
             puzzle_path.to_string(),
         ],
-        day,
+        puzzle,
     );
 
-    let output = call_aoc_cli(&args)?;
+    paths::create_parent_dir(&input_path).map_err(AocCommandError::IO)?;
+    paths::create_parent_dir(&puzzle_path).map_err(AocCommandError::IO)?;
+
+    let result = call_aoc_cli(&args, false).and_then(|output| {
+        let input = fs::read_to_string(&download_path).unwrap_or_default();
+        inputs::validate(&input).map_err(AocCommandError::InvalidInput)?;
+        fs::rename(&download_path, &input_path).map_err(AocCommandError::IO)?;
+        Ok(output)
+    });
+
+    // a failed download must not leave a partial file behind or replace the previous input.
+    let _ = fs::remove_file(&download_path);
+    let output = result?;
+
     println!("---");
     println!("🎄 Successfully wrote input to \"{}\".", &input_path);
     println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+pub fn submit(puzzle: PuzzleId, part: Part, result: &str) -> Result<Verdict, AocCommandError> {
     // workaround: the argument order is inverted for submit.
-    let mut args = build_args("submit", &[], day);
+    let mut args = build_args("submit", &[], puzzle);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
-}
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
-}
+    // capture the response so it can be parsed into a verdict.
+    let output = call_aoc_cli(&args, true)?;
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+    Verdict::from_message(&String::from_utf8_lossy(&output.stdout))
+        .ok_or(AocCommandError::UnknownResponse(output))
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
-}
-
-fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
+fn build_args(command: &str, args: &[String], puzzle: PuzzleId) -> Vec<String> {
     let mut cmd_args = args.to_vec();
 
-    if let Some(year) = get_year() {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
-
-    cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
+    cmd_args.append(&mut vec![
+        "--year".into(),
+        puzzle.year.to_string(),
+        "--day".into(),
+        puzzle.day.to_string(),
+        command.into(),
+    ]);
 
     cmd_args
 }
 
-fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
+/// Runs aoc-cli with its output shown in the terminal. With `capture_stdout`, its standard output
+/// is returned in the [`Output`] instead.
+fn call_aoc_cli(args: &[String], capture_stdout: bool) -> Result<Output, AocCommandError> {
     // println!("Calling >aoc with: {}", args.join(" "));
     let output = Command::new("aoc")
         .args(args)
-        .stdout(Stdio::inherit())
+        .stdout(if capture_stdout {
+            Stdio::piped()
+        } else {
+            Stdio::inherit()
+        })
         .stderr(Stdio::inherit())
         .output()
         .map_err(|_| AocCommandError::CommandNotCallable)?;
@@ -123,3 +146,175 @@ fn call_aoc_cli(args: &[String]) -> Result<Output, AocCommandError> {
         Err(AocCommandError::BadExitStatus(output))
     }
 }
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(feature = "test_lib", unix))]
+mod tests {
+    use super::{build_args, check, download, read, submit, AocCommandError};
+    use crate::template::{fake_aoc::FakeAoc, inputs::InputError, Verdict};
+    use crate::{part, puzzle_id};
+
+    /// Writes the files passed via `--input-file` and `--puzzle-file`, like aoc-cli does.
+    const DOWNLOAD_SCRIPT: &str = r#"
+while [ $# -gt 0 ]; do
+  case "$1" in
+    --input-file) printf '3   4\n4   3\n' > "$2"; shift;;
+    --puzzle-file) printf 'The puzzle.\n' > "$2"; shift;;
+  esac
+  shift
+done"#;
+
+    fn strings(args: &[&str]) -> Vec<String> {
+        args.iter().map(|x| (*x).to_string()).collect()
+    }
+
+    #[test]
+    fn builds_args_with_year() {
+        assert_eq!(
+            build_args("read", &["--description-only".into()], puzzle_id!(2024, 1)),
+            strings(&[
+                "--description-only",
+                "--year",
+                "2024",
+                "--day",
+                "01",
+                "read"
+            ])
+        );
+    }
+
+    #[test]
+    fn reports_missing_aoc_cli() {
+        let _fake = FakeAoc::not_installed();
+        assert!(matches!(check(), Err(AocCommandError::CommandNotFound)));
+        assert!(matches!(
+            read(puzzle_id!(2024, 1)),
+            Err(AocCommandError::CommandNotCallable)
+        ));
+    }
+
+    #[test]
+    fn reads_puzzle() {
+        let fake = FakeAoc::install("");
+        assert!(check().is_ok());
+        read(puzzle_id!(2024, 1)).unwrap();
+
+        let puzzle_path = fake.data_dir().join("2024/puzzles/01.md");
+        assert_eq!(
+            fake.calls(),
+            vec![
+                strings(&["-V"]),
+                strings(&[
+                    "--description-only",
+                    "--puzzle-file",
+                    &puzzle_path.to_string_lossy(),
+                    "--year",
+                    "2024",
+                    "--day",
+                    "01",
+                    "read"
+                ])
+            ]
+        );
+    }
+
+    #[test]
+    fn downloads_via_temporary_file() {
+        let fake = FakeAoc::install(DOWNLOAD_SCRIPT);
+        download(puzzle_id!(2024, 3)).unwrap();
+
+        let input_path = fake.data_dir().join("2024/inputs/03.txt");
+        let puzzle_path = fake.data_dir().join("2024/puzzles/03.md");
+        assert_eq!(
+            fake.last_call(),
+            strings(&[
+                "--overwrite",
+                "--input-file",
+                &format!("{}.download", input_path.display()),
+                "--puzzle-file",
+                &puzzle_path.to_string_lossy(),
+                "--year",
+                "2024",
+                "--day",
+                "03",
+                "download"
+            ])
+        );
+        assert_eq!(
+            fake.read_data_file("2024/inputs/03.txt").as_deref(),
+            Some("3   4\n4   3\n")
+        );
+        assert_eq!(
+            fake.read_data_file("2024/puzzles/03.md").as_deref(),
+            Some("The puzzle.\n")
+        );
+        assert_eq!(fake.read_data_file("2024/inputs/03.txt.download"), None);
+    }
+
+    #[test]
+    fn keeps_previous_input_if_download_is_invalid() {
+        let fake = FakeAoc::install(r#"printf 'Please log in to get your puzzle input.\n' > "$3""#);
+        fake.write_data_file("2024/inputs/03.txt", "1 2\n");
+
+        assert!(matches!(
+            download(puzzle_id!(2024, 3)),
+            Err(AocCommandError::InvalidInput(InputError::NotLoggedIn))
+        ));
+        assert_eq!(
+            fake.read_data_file("2024/inputs/03.txt").as_deref(),
+            Some("1 2\n")
+        );
+        assert_eq!(fake.read_data_file("2024/inputs/03.txt.download"), None);
+    }
+
+    #[test]
+    fn maps_bad_exit_status() {
+        let fake = FakeAoc::install("exit 2");
+        fake.write_data_file("2024/inputs/03.txt", "1 2\n");
+
+        match download(puzzle_id!(2024, 3)) {
+            Err(AocCommandError::BadExitStatus(output)) => {
+                assert_eq!(output.status.code(), Some(2));
+            }
+            x => panic!("expected BadExitStatus, got {x:?}"),
+        }
+        assert_eq!(
+            fake.read_data_file("2024/inputs/03.txt").as_deref(),
+            Some("1 2\n")
+        );
+
+        assert!(matches!(
+            submit(puzzle_id!(2024, 3), part!(1), "42"),
+            Err(AocCommandError::BadExitStatus(_))
+        ));
+    }
+
+    #[test]
+    fn submits_with_inverted_argument_order() {
+        let fake =
+            FakeAoc::install(r#"echo "That's the right answer!  You are one gold star closer.""#);
+
+        assert!(matches!(
+            submit(puzzle_id!(2024, 5), part!(2), "143"),
+            Ok(Verdict::Correct)
+        ));
+        assert_eq!(
+            fake.last_call(),
+            strings(&["--year", "2024", "--day", "05", "submit", "2", "143"])
+        );
+    }
+
+    #[test]
+    fn reports_unknown_submit_responses() {
+        let _fake = FakeAoc::install("echo 'Something unexpected happened.'");
+
+        match submit(puzzle_id!(2024, 5), part!(1), "143") {
+            Err(AocCommandError::UnknownResponse(output)) => assert_eq!(
+                String::from_utf8_lossy(&output.stdout),
+                "Something unexpected happened.\n"
+            ),
+            x => panic!("expected UnknownResponse, got {x:?}"),
+        }
+    }
+}
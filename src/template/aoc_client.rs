@@ -0,0 +1,483 @@
+/// Built-in HTTP client for the Advent of Code website. Alternative to the "aoc-cli" wrapper.
+use std::{env, fmt::Display, fs, io, path::PathBuf, time::Duration};
+
+use crate::template::inputs::{self, InputError};
+use crate::template::{paths, Part, PuzzleId, Verdict, Year};
+
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "github.com/dreadster3/aoc2024";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    BadStatus(u16),
+    Transport(String),
+    UnknownResponse(String),
+    InvalidInput(InputError),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found. Set `AOC_SESSION` or create \"~/.adventofcode.session\"."
+            ),
+            AocClientError::BadStatus(status) => {
+                write!(f, "adventofcode.com responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "request failed: {e}"),
+            AocClientError::UnknownResponse(message) => {
+                write!(f, "could not parse response: {message}")
+            }
+            AocClientError::InvalidInput(e) => write!(f, "invalid input: {e}"),
+            AocClientError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        match e {
+            ureq::Error::Status(status, _) => AocClientError::BadStatus(status),
+            ureq::Error::Transport(e) => AocClientError::Transport(e.to_string()),
+        }
+    }
+}
+
+/// An authenticated session against the Advent of Code website.
+pub struct AocClient {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str, user_agent: &str) -> Self {
+        let agent = ureq::AgentBuilder::new()
+            .user_agent(user_agent)
+            .timeout(Duration::from_secs(30))
+            .build();
+
+        Self {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Creates a client from the environment.
+    /// The session cookie is read from `AOC_SESSION`, falling back to the `~/.adventofcode.session` file used by aoc-cli.
+    /// `AOC_BASE_URL` can be set to point the client at a different server.
+    /// Requests identify this repository as their source, forks can set `AOC_USER_AGENT` to identify themselves.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let session = get_session().ok_or(AocClientError::SessionNotFound)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.into());
+        let user_agent = env::var("AOC_USER_AGENT")
+            .ok()
+            .filter(|x| !x.trim().is_empty())
+            .unwrap_or_else(|| USER_AGENT.into());
+        Ok(Self::new(&base_url, &session, &user_agent))
+    }
+
+    /// Fetches the personal puzzle input for a day.
+    pub fn get_input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let url = format!("{}/input", self.day_url(puzzle));
+        Ok(self
+            .authorized(self.agent.get(&url))
+            .call()?
+            .into_string()?)
+    }
+
+    /// Fetches the puzzle description for a day and converts it to markdown.
+    pub fn get_puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self
+            .authorized(self.agent.get(&self.day_url(puzzle)))
+            .call()?
+            .into_string()?;
+
+        Ok(extract_elements(&html, "article")
+            .iter()
+            .map(|article| html_to_markdown(article))
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
+    /// Posts an answer and returns the message the website responded with.
+    pub fn post_answer(
+        &self,
+        puzzle: PuzzleId,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, AocClientError> {
+        let url = format!("{}/answer", self.day_url(puzzle));
+        let html = self
+            .authorized(self.agent.post(&url))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?
+            .into_string()?;
+
+        Ok(extract_elements(&html, "article")
+            .first()
+            .map_or_else(|| html_to_text(&html), |article| html_to_text(article)))
+    }
+
+    /// Fetches the JSON of a private leaderboard.
+    pub fn get_leaderboard(&self, year: Year, id: &str) -> Result<String, AocClientError> {
+        let url = format!(
+            "{}/{year}/leaderboard/private/view/{id}.json",
+            self.base_url
+        );
+        Ok(self
+            .authorized(self.agent.get(&url))
+            .call()?
+            .into_string()?)
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year,
+            puzzle.day.into_inner()
+        )
+    }
+
+    fn authorized(&self, request: ureq::Request) -> ureq::Request {
+        request.set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+fn get_session() -> Option<String> {
+    let session = match env::var("AOC_SESSION") {
+        Ok(session) => session,
+        Err(_) => {
+            let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+            fs::read_to_string(PathBuf::from(home).join(".adventofcode.session")).ok()?
+        }
+    };
+
+    let session = session.trim();
+    (!session.is_empty()).then(|| session.to_string())
+}
+
+pub fn read(puzzle: PuzzleId) -> Result<String, AocClientError> {
+    AocClient::from_env()?.get_puzzle(puzzle)
+}
+
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = paths::input(puzzle);
+    let puzzle_path = paths::puzzle(puzzle);
+
+    let input = client.get_input(puzzle)?;
+    inputs::validate(&input).map_err(AocClientError::InvalidInput)?;
+
+    paths::create_parent_dir(&puzzle_path)?;
+    paths::create_parent_dir(&input_path)?;
+    fs::write(&puzzle_path, client.get_puzzle(puzzle)?)?;
+    fs::write(&input_path, input)?;
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+pub fn submit(puzzle: PuzzleId, part: Part, result: &str) -> Result<Verdict, AocClientError> {
+    let client = AocClient::from_env()?;
+    let message = client.post_answer(puzzle, part, result)?;
+    Verdict::from_message(&message).ok_or(AocClientError::UnknownResponse(message))
+}
+
+pub fn leaderboard(year: Year, id: &str) -> Result<String, AocClientError> {
+    AocClient::from_env()?.get_leaderboard(year, id)
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Returns the inner html of all (non-nested) elements with the given tag name.
+fn extract_elements<'a>(html: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{tag}");
+    let close = format!("</{tag}>");
+
+    let mut elements = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(&open) {
+        let Some(content_start) = rest[start..].find('>').map(|i| start + i + 1) else {
+            break;
+        };
+        let Some(content_end) = rest[content_start..]
+            .find(&close)
+            .map(|i| content_start + i)
+        else {
+            break;
+        };
+        elements.push(&rest[content_start..content_end]);
+        rest = &rest[content_end + close.len()..];
+    }
+
+    elements
+}
+
+fn get_attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let pattern = format!("{name}=\"");
+    let start = tag.find(&pattern)? + pattern.len();
+    let end = tag[start..].find('"')? + start;
+    Some(&tag[start..end])
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Appends text to the output. Outside of preformatted blocks, runs of whitespace collapse into a single space.
+fn push_text(out: &mut String, text: &str, preformatted: bool) {
+    let text = decode_entities(text);
+
+    if preformatted {
+        out.push_str(&text);
+        return;
+    }
+
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !(out.is_empty() || out.ends_with(' ') || out.ends_with('\n')) {
+                out.push(' ');
+            }
+        } else {
+            out.push(c);
+        }
+    }
+}
+
+/// Converts the subset of html used in puzzle descriptions to markdown.
+fn html_to_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut preformatted = false;
+    let mut links: Vec<String> = vec![];
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        push_text(&mut out, &rest[..tag_start], preformatted);
+
+        let Some(tag_end) = rest[tag_start..].find('>').map(|i| tag_start + i) else {
+            break;
+        };
+
+        let tag = &rest[tag_start + 1..tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let is_closing = tag.starts_with('/');
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default();
+
+        match (name, is_closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2" | "p", true) => out.push_str("\n\n"),
+            ("ul", true) => out.push('\n'),
+            ("pre", false) => {
+                preformatted = true;
+                out.push_str("```\n");
+            }
+            ("pre", true) => {
+                preformatted = false;
+                if !out.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+            }
+            ("code", _) if !preformatted => out.push('`'),
+            ("em", _) if !preformatted => out.push('*'),
+            ("li", false) => out.push_str("- "),
+            ("li" | "br", _) => out.push('\n'),
+            ("a", false) => {
+                links.push(get_attribute(tag, "href").unwrap_or_default().to_string());
+                out.push('[');
+            }
+            ("a", true) => {
+                let href = links.pop().unwrap_or_default();
+                out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    push_text(&mut out, rest, preformatted);
+
+    format!("{}\n", out.trim_end())
+}
+
+/// Strips all tags from html and collapses whitespace.
+fn html_to_text(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+
+    while let Some(tag_start) = rest.find('<') {
+        push_text(&mut out, &rest[..tag_start], false);
+        let Some(tag_end) = rest[tag_start..].find('>') else {
+            break;
+        };
+        rest = &rest[tag_start + tag_end + 1..];
+    }
+
+    push_text(&mut out, rest, false);
+    out.trim().to_string()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::{html_to_markdown, AocClient, AocClientError, USER_AGENT};
+    use crate::{part, puzzle_id, year};
+
+    /// Starts a local stand-in for the website that answers a single request.
+    /// Joining the handle returns the raw request that was received.
+    fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(len) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = len.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" {
+                    break;
+                }
+            }
+
+            let mut request_body = vec![0; content_length];
+            reader.read_exact(&mut request_body).unwrap();
+            request.push_str(&String::from_utf8(request_body).unwrap());
+
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn fetches_input_with_session_cookie() {
+        let (base_url, server) = serve_once("200 OK", "1 2 3\n");
+        let client = AocClient::new(&base_url, "abc", USER_AGENT);
+
+        let input = client.get_input(puzzle_id!(2024, 6)).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "1 2 3\n");
+        assert!(request.starts_with("GET /2024/day/6/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+        assert!(request
+            .to_lowercase()
+            .contains("user-agent: github.com/dreadster3/aoc2024"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<main><article class=\"day-desc\"><h2>--- Day 6: Guard Gallivant ---</h2><p>Part one.</p></article>\n<p>Your puzzle answer was <code>41</code>.</p><article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Part two.</p></article></main>",
+        );
+        let client = AocClient::new(&base_url, "abc", USER_AGENT);
+
+        let puzzle = client.get_puzzle(puzzle_id!(2024, 6)).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(
+            puzzle,
+            "## --- Day 6: Guard Gallivant ---\n\nPart one.\n\n## --- Part Two ---\n\nPart two.\n"
+        );
+    }
+
+    #[test]
+    fn posts_answer() {
+        let (base_url, server) = serve_once(
+            "200 OK",
+            "<main>\n<article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article>\n</main>",
+        );
+        let client = AocClient::new(&base_url, "abc", USER_AGENT);
+
+        let message = client
+            .post_answer(puzzle_id!(2024, 6), part!(1), "41")
+            .unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(
+            message,
+            "That's the right answer! You are one gold star closer."
+        );
+        assert!(request.starts_with("POST /2024/day/6/answer HTTP/1.1"));
+        assert!(request.ends_with("level=1&answer=41"));
+    }
+
+    #[test]
+    fn fetches_leaderboard_json() {
+        let (base_url, server) = serve_once("200 OK", "{\"event\":\"2024\"}");
+        let client = AocClient::new(&base_url, "abc", USER_AGENT);
+
+        let json = client.get_leaderboard(year!(2024), "1234").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(json, "{\"event\":\"2024\"}");
+        assert!(request.starts_with("GET /2024/leaderboard/private/view/1234.json HTTP/1.1"));
+    }
+
+    #[test]
+    fn maps_bad_status() {
+        let (base_url, server) = serve_once("404 Not Found", "Not found");
+        let client = AocClient::new(&base_url, "abc", USER_AGENT);
+
+        let result = client.get_input(puzzle_id!(2024, 6));
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(AocClientError::BadStatus(404))));
+    }
+
+    #[test]
+    fn converts_html_to_markdown() {
+        let html = "<h2>--- Day 1 ---</h2><p>The <em>first</em> list is <code>3 4</code>, see <a href=\"/2024/about\">about</a>:</p>\n<pre><code>3   4\n<em>4</em>   3\n</code></pre>\n<ul><li>one &amp; two</li><li>&lt;three&gt;</li></ul>";
+
+        assert_eq!(
+            html_to_markdown(html),
+            "## --- Day 1 ---\n\nThe *first* list is `3 4`, see [about](/2024/about):\n\n```\n3   4\n4   3\n```\n\n- one & two\n- <three>\n"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+/// Keeps copies of files before they are overwritten or removed.
+///
+/// Backups of one command share a folder named after the current unix timestamp, e.g. `./backups/1733461200/`.
+/// If that folder exists already, a counter is appended, e.g. `./backups/1733461200-2/`.
+/// Below it, files keep their path, e.g. `./backups/1733461200/src/bin/2024-05.rs`.
+use std::{
+    fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::template::timestamp;
+
+const BACKUP_DIR: &str = "backups";
+
+pub struct Backup {
+    dir: PathBuf,
+}
+
+impl Backup {
+    /// Creates a backup folder for the current time in `./backups`.
+    pub fn new() -> Self {
+        Self::in_dir(BACKUP_DIR)
+    }
+
+    /// Creates a backup folder for the current time in `root`.
+    pub fn in_dir(root: impl AsRef<Path>) -> Self {
+        let timestamp = timestamp::now();
+        let dir = (1..)
+            .map(|i| match i {
+                1 => root.as_ref().join(timestamp.to_string()),
+                i => root.as_ref().join(format!("{timestamp}-{i}")),
+            })
+            .find(|dir| !dir.exists())
+            .expect("a free backup folder");
+
+        Self { dir }
+    }
+
+    /// The backup folder of this command.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Path of the backup of a file. Absolute paths are stored relative to the backup folder.
+    fn target(&self, path: &str) -> PathBuf {
+        let relative: PathBuf = Path::new(path)
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .collect();
+        self.dir.join(relative)
+    }
+
+    /// Copies a file to the backup folder. Returns the path of the copy, or `None` if the file does not exist.
+    pub fn save(&self, path: &str) -> io::Result<Option<String>> {
+        if !Path::new(path).is_file() {
+            return Ok(None);
+        }
+
+        let target = self.target(path);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(path, &target)?;
+
+        Ok(Some(target.to_string_lossy().into_owned()))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::Backup;
+
+    #[test]
+    fn keeps_paths_below_the_backup_folder() {
+        let backup = Backup::in_dir("backups");
+        let target = backup.target("./src/bin/2024-05.rs");
+        assert!(target.starts_with("backups"));
+        assert!(target.ends_with("src/bin/2024-05.rs"));
+        assert!(backup
+            .target("/home/me/aoc/2024/inputs/05.txt")
+            .ends_with("home/me/aoc/2024/inputs/05.txt"));
+    }
+
+    #[test]
+    fn copies_existing_files() {
+        let root = env::temp_dir().join(format!("aoc-backup-{}", process::id()));
+        let file = root.join("05.txt");
+        fs::create_dir_all(&root).unwrap();
+        fs::write(&file, "1 2 3\n").unwrap();
+
+        let backup = Backup::in_dir(root.join("backups"));
+        let copy = backup.save(file.to_str().unwrap()).unwrap().unwrap();
+        assert_eq!(fs::read_to_string(copy).unwrap(), "1 2 3\n");
+
+        // a second backup never replaces the first one, even within the same second.
+        let second = Backup::in_dir(root.join("backups"));
+        assert_ne!(second.dir(), backup.dir());
+        assert_eq!(
+            backup
+                .save(root.join("missing.txt").to_str().unwrap())
+                .unwrap(),
+            None
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
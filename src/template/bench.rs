@@ -0,0 +1,217 @@
+/// Benchmarks solution parts for `cargo bench`, see the `benches/<year>-<day>.rs` files created by `cargo scaffold --bench`.
+///
+/// Each part is run against the input and the example of the part. After a warm-up, runs are grouped into
+/// samples of at least a millisecond to even out timer resolution. Samples outside of 1.5 times the
+/// interquartile range are dropped as outliers, the mean of the rest is reported like `cargo time` reports it.
+use std::fmt::Display;
+use std::fs;
+use std::hint::black_box;
+use std::io::{stdout, Write};
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "encrypted-inputs")]
+use crate::template::encryption;
+use crate::template::example_tests;
+use crate::template::timings::Timings;
+use crate::template::{paths, Part, PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const WARM_UP_TIME: Duration = Duration::from_millis(500);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(3);
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(1);
+const MIN_SAMPLES: usize = 10;
+const MAX_SAMPLES: usize = 100;
+
+/// Statistics of the time per run of a solution part, in nanoseconds.
+#[derive(Debug, PartialEq)]
+pub struct Summary {
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+    pub samples: usize,
+    pub outliers: usize,
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    #[allow(clippy::cast_precision_loss)]
+    let position = q * (sorted.len() - 1) as f64;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - position.floor())
+}
+
+/// Summarizes samples of the time per run. Outliers are excluded from the mean and standard deviation.
+pub fn summarize(samples: &[f64]) -> Summary {
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable_by(f64::total_cmp);
+
+    let (q1, q3) = (quantile(&sorted, 0.25), quantile(&sorted, 0.75));
+    let fence = 1.5 * (q3 - q1);
+    let kept: Vec<f64> = sorted
+        .iter()
+        .copied()
+        .filter(|x| (q1 - fence..=q3 + fence).contains(x))
+        .collect();
+
+    #[allow(clippy::cast_precision_loss)]
+    let count = kept.len() as f64;
+    let mean = kept.iter().sum::<f64>() / count;
+    let variance = kept.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count;
+
+    Summary {
+        mean,
+        median: quantile(&sorted, 0.5),
+        std_dev: variance.sqrt(),
+        samples: samples.len(),
+        outliers: samples.len() - kept.len(),
+    }
+}
+
+/// Runs a function repeatedly and returns the time per run of each sample, in nanoseconds.
+fn measure<T>(func: impl Fn(&str) -> T, input: &str) -> Vec<f64> {
+    let warm_up = Instant::now();
+    let mut runs: u32 = 0;
+    while runs == 0 || warm_up.elapsed() < WARM_UP_TIME {
+        black_box(func(black_box(input)));
+        runs += 1;
+    }
+    let estimate = warm_up.elapsed() / runs;
+
+    let runs_per_sample = u32::try_from(MIN_SAMPLE_TIME.as_nanos() / estimate.as_nanos().max(1))
+        .unwrap_or(u32::MAX)
+        .max(1);
+    let sample_count = usize::try_from(
+        MEASUREMENT_TIME.as_nanos() / (estimate * runs_per_sample).as_nanos().max(1),
+    )
+    .unwrap_or(MAX_SAMPLES)
+    .clamp(MIN_SAMPLES, MAX_SAMPLES);
+
+    (0..sample_count)
+        .map(|_| {
+            let timer = Instant::now();
+            for _ in 0..runs_per_sample {
+                black_box(func(black_box(input)));
+            }
+            timer.elapsed().as_nanos() as f64 / f64::from(runs_per_sample)
+        })
+        .collect()
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn format_nanos(nanos: f64) -> String {
+    format!("{:.1?}", Duration::from_nanos(nanos as u64))
+}
+
+/// Reads a data file, or `None` if it is missing or empty.
+fn read_if_present(path: &str) -> Option<String> {
+    let contents = fs::read_to_string(path).ok();
+
+    #[cfg(feature = "encrypted-inputs")]
+    let contents = contents.or_else(|| encryption::read_encrypted(path).ok());
+
+    contents.filter(|x| !x.trim().is_empty())
+}
+
+/// Inputs to bench a part with: the input of the day and the example of the part, unless its answers file skips the part.
+fn bench_inputs(puzzle: PuzzleId, part: Part) -> Vec<(String, String)> {
+    let mut inputs: Vec<(String, String)> = read_if_present(&paths::input(puzzle))
+        .map(|input| ("input".to_string(), input))
+        .into_iter()
+        .collect();
+
+    let examples = example_tests::find_examples(puzzle);
+    let example = example_tests::example_of_part(&examples, part)
+        .filter(|example| example.answer(part).is_some())
+        .and_then(|example| read_if_present(&example.path(puzzle)));
+
+    if let Some(example) = example {
+        inputs.push(("example".to_string(), example));
+    }
+
+    inputs
+}
+
+/// The timing of a part stored by `cargo time --store`.
+fn stored_timing(puzzle: PuzzleId, part: Part) -> Option<String> {
+    let timing = Timings::read_from_file()
+        .data
+        .into_iter()
+        .find(|t| t.puzzle == puzzle)?;
+
+    match part {
+        Part::One => timing.part_1,
+        Part::Two => timing.part_2,
+    }
+}
+
+/// Prints the heading of a day's benchmarks.
+pub fn print_header(puzzle: PuzzleId) {
+    let title = format!("Day {} ({})", puzzle.day, puzzle.year);
+    println!("{ANSI_BOLD}{title}{ANSI_RESET}");
+    println!("{}", "-".repeat(title.len()));
+}
+
+/// Benches a part of a solution against the input and the examples of its day.
+pub fn bench_part<T: Display>(puzzle: PuzzleId, part: Part, func: impl Fn(&str) -> Option<T>) {
+    let inputs = bench_inputs(puzzle, part);
+
+    if inputs.is_empty() {
+        println!("Part {part}: ✖ no input or examples found");
+        return;
+    }
+
+    for (label, input) in inputs {
+        print!("Part {part} ({label}): {ANSI_ITALIC}benching{ANSI_RESET}");
+        let _ = stdout().flush();
+
+        let summary = summarize(&measure(&func, &input));
+
+        let stored = match stored_timing(puzzle, part) {
+            Some(stored) if label == "input" => format!(", stored {stored}"),
+            _ => String::new(),
+        };
+
+        println!(
+            "\rPart {part} ({label}): {ANSI_BOLD}{}{ANSI_RESET} ± {} (median {}, {} samples, {} outliers{stored})",
+            format_nanos(summary.mean),
+            format_nanos(summary.std_dev),
+            format_nanos(summary.median),
+            summary.samples,
+            summary.outliers,
+        );
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_nanos, summarize, Summary};
+
+    #[test]
+    fn summarizes_samples() {
+        assert_eq!(
+            summarize(&[4.0, 2.0, 6.0, 4.0]),
+            Summary {
+                mean: 4.0,
+                median: 4.0,
+                std_dev: 2.0_f64.sqrt(),
+                samples: 4,
+                outliers: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn drops_outliers_from_the_mean() {
+        let summary = summarize(&[10.0, 11.0, 10.0, 9.0, 10.0, 500.0]);
+        assert_eq!(summary.outliers, 1);
+        assert!((summary.mean - 10.0).abs() < f64::EPSILON);
+        assert!((summary.median - 10.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn formats_like_stored_timings() {
+        assert_eq!(format_nanos(39.4), "39.0ns");
+        assert_eq!(format_nanos(1_250_000.0), "1.2ms");
+    }
+}
@@ -0,0 +1,139 @@
+/// Bench targets that `scaffold --bench` creates for `cargo bench --bench <year>-<day>`.
+///
+/// A bench includes the solution binary as a module, wherever `AOC_BIN_DIR` puts it,
+/// and runs its parts with [`crate::template::bench`].
+/// Cargo only runs a bench without the default test harness if it is declared in `Cargo.toml`,
+/// so every bench gets a `[[bench]]` entry that is inserted above `[dependencies]`.
+use std::path::Path;
+
+use crate::template::{paths, Part, PuzzleId};
+
+pub const MANIFEST: &str = "./Cargo.toml";
+
+/// The `[[bench]]` entry of a puzzle's bench.
+fn manifest_entry(puzzle: PuzzleId) -> String {
+    format!("[[bench]]\nname = \"{puzzle}\"\nharness = false\n\n")
+}
+
+/// Path of the solution of a puzzle relative to its bench, e.g. `../src/bin/2024-01.rs`.
+pub fn solution_path(puzzle: PuzzleId) -> String {
+    let bench = paths::bench(puzzle);
+    let bench_dir = Path::new(&bench)
+        .parent()
+        .map_or_else(|| ".".into(), |dir| dir.to_string_lossy().into_owned());
+    paths::relative_to(&paths::bin(puzzle), &bench_dir)
+}
+
+/// Contents of the bench of a puzzle that includes the solution at `solution_path`, relative to the bench.
+pub fn contents(puzzle: PuzzleId, solution_path: &str) -> String {
+    let parts: String = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| puzzle.has_part(*part))
+        .map(|part| {
+            let function = match part {
+                Part::One => "part_one",
+                Part::Two => "part_two",
+            };
+            format!(
+                "    bench_part(puzzle, advent_of_code::part!({part}), solution::{function});\n"
+            )
+        })
+        .collect();
+
+    format!(
+        "//! Benchmarks of day {day} ({year}). Run with `cargo bench --bench {puzzle}`.\n\
+        \n\
+        // the solution is linted as its own binary, its `main` and tests are not used here.\n\
+        #[allow(dead_code, unused_imports)]\n\
+        #[path = \"{solution_path}\"]\n\
+        mod solution;\n\
+        \n\
+        fn main() {{\n    \
+            use advent_of_code::template::bench::*;\n    \
+            let puzzle = advent_of_code::puzzle_id!({year}, {day});\n    \
+            print_header(puzzle);\n\
+        {parts}}}\n",
+        day = puzzle.day.into_inner(),
+        year = puzzle.year,
+    )
+}
+
+/// Adds the `[[bench]]` entry of a puzzle to a manifest. Returns `None` if it is declared already.
+pub fn add_to_manifest(manifest: &str, puzzle: PuzzleId) -> Option<String> {
+    let entry = manifest_entry(puzzle);
+    if manifest.contains(&entry) {
+        return None;
+    }
+
+    Some(match manifest.find("\n[dependencies]") {
+        Some(index) => format!("{}{entry}{}", &manifest[..=index], &manifest[index + 1..]),
+        None => format!("{}\n\n{}\n", manifest.trim_end(), entry.trim_end()),
+    })
+}
+
+/// Removes the `[[bench]]` entry of a puzzle from a manifest. Returns `None` if there is none.
+pub fn remove_from_manifest(manifest: &str, puzzle: PuzzleId) -> Option<String> {
+    let entry = manifest_entry(puzzle);
+    if manifest.contains(&entry) {
+        return Some(manifest.replacen(&entry, "", 1));
+    }
+
+    // the last entry of a manifest without dependencies is not followed by a blank line.
+    let last = format!("\n\n{}", entry.trim_end());
+    manifest
+        .trim_end()
+        .strip_suffix(&last)
+        .map(|rest| format!("{rest}\n"))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{add_to_manifest, contents, remove_from_manifest};
+    use crate::puzzle_id;
+
+    const MANIFEST: &str =
+        "[package]\nname = \"advent_of_code\"\n\n[dependencies]\nregex = \"1\"\n";
+
+    #[test]
+    fn adds_entries_above_dependencies() {
+        let manifest = add_to_manifest(MANIFEST, puzzle_id!(2024, 6)).unwrap();
+        assert_eq!(
+            manifest,
+            "[package]\nname = \"advent_of_code\"\n\n[[bench]]\nname = \"2024-06\"\nharness = false\n\n[dependencies]\nregex = \"1\"\n"
+        );
+        assert_eq!(add_to_manifest(&manifest, puzzle_id!(2024, 6)), None);
+        assert_eq!(
+            remove_from_manifest(&manifest, puzzle_id!(2024, 6)).as_deref(),
+            Some(MANIFEST)
+        );
+        assert_eq!(remove_from_manifest(MANIFEST, puzzle_id!(2024, 6)), None);
+    }
+
+    #[test]
+    fn adds_entries_to_manifests_without_dependencies() {
+        let manifest = "[package]\nname = \"advent_of_code\"\n";
+        let with_bench = add_to_manifest(manifest, puzzle_id!(2024, 6)).unwrap();
+        assert_eq!(
+            with_bench,
+            "[package]\nname = \"advent_of_code\"\n\n[[bench]]\nname = \"2024-06\"\nharness = false\n"
+        );
+        assert_eq!(
+            remove_from_manifest(&with_bench, puzzle_id!(2024, 6)),
+            Some("[package]\nname = \"advent_of_code\"\n".into())
+        );
+    }
+
+    #[test]
+    fn benches_every_part_of_a_day() {
+        let bench = contents(puzzle_id!(2024, 6), "../src/bin/2024-06.rs");
+        assert!(bench.contains("#[path = \"../src/bin/2024-06.rs\"]"));
+        assert!(bench.contains("let puzzle = advent_of_code::puzzle_id!(2024, 6);"));
+        assert!(bench.contains("advent_of_code::part!(1), solution::part_one);"));
+        assert!(bench.contains("advent_of_code::part!(2), solution::part_two);"));
+
+        let bench = contents(puzzle_id!(2024, 25), "../src/bin/2024-25.rs");
+        assert!(!bench.contains("part_two"));
+    }
+}
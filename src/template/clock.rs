@@ -0,0 +1,208 @@
+/// Module that determines the current puzzle from the time in the server time zone.
+/// The current time can be overridden with `AOC_NOW` to replay past events.
+use std::env;
+
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
+
+use crate::template::{Day, PuzzleId, Year, SERVER_UTC_OFFSET};
+
+fn server_offset() -> FixedOffset {
+    FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600).expect("server offset is in range")
+}
+
+/// Parses an `AOC_NOW` value, either a RFC 3339 timestamp or a date at midnight server time.
+fn parse_now(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+
+    if let Ok(now) = DateTime::parse_from_rfc3339(value) {
+        return Some(now.with_timezone(&server_offset()));
+    }
+
+    let date = NaiveDate::parse_from_str(value, "%Y-%m-%d").ok()?;
+    server_offset()
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .single()
+}
+
+fn now_from_env() -> Option<DateTime<FixedOffset>> {
+    env::var("AOC_NOW").ok().and_then(|value| parse_now(&value))
+}
+
+/// Returns the current time in the server time zone.
+/// A valid `AOC_NOW` takes precedence over the system clock.
+pub fn now() -> DateTime<FixedOffset> {
+    now_from_env().unwrap_or_else(|| Utc::now().with_timezone(&server_offset()))
+}
+
+/// Whether a valid `AOC_NOW` replaces the system clock. The replaced clock stands still.
+pub fn is_overridden() -> bool {
+    now_from_env().is_some()
+}
+
+/// Returns the puzzle of a date if the date is a day of its year's event.
+fn puzzle_on(date: NaiveDate) -> Option<PuzzleId> {
+    if date.month() != 12 {
+        return None;
+    }
+
+    let year = Year::new(u16::try_from(date.year()).ok()?)?;
+    let day = Day::new(u8::try_from(date.day()).ok()?)?;
+    let puzzle = PuzzleId::new(year, day);
+    puzzle.is_in_event().then_some(puzzle)
+}
+
+/// Returns the puzzle that is unlocked at `now`, if `now` is during an event.
+pub fn today_at(now: DateTime<FixedOffset>) -> Option<PuzzleId> {
+    puzzle_on(now.with_timezone(&server_offset()).date_naive())
+}
+
+/// Returns the puzzle to wait for at `now`, along with its unlock time.
+/// This is the puzzle of the current day if it is already live, otherwise the first puzzle that unlocks after `now`.
+/// Puzzles unlock at midnight in the server time zone.
+pub fn next_unlock_after(now: DateTime<FixedOffset>) -> Option<(PuzzleId, DateTime<FixedOffset>)> {
+    let now = now.with_timezone(&server_offset());
+    let today = now.date_naive();
+    let tomorrow = today.succ_opt()?;
+
+    let date = if puzzle_on(today).is_some() {
+        today
+    } else if puzzle_on(tomorrow).is_some() {
+        tomorrow
+    } else if tomorrow.month() < 12 {
+        NaiveDate::from_ymd_opt(tomorrow.year(), 12, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(tomorrow.year() + 1, 12, 1)?
+    };
+
+    let unlock = now
+        .timezone()
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0)?)
+        .single()?;
+
+    Some((puzzle_on(date)?, unlock))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use chrono::{DateTime, FixedOffset, TimeZone};
+
+    use super::{next_unlock_after, parse_now, today_at};
+    use crate::puzzle_id;
+
+    fn server_time(year: i32, month: u32, day: u32, hour: u32, min: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(-5 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(year, month, day, hour, min, 0)
+            .unwrap()
+    }
+
+    mod today {
+        use chrono::{FixedOffset, TimeZone};
+
+        use super::{server_time, today_at};
+        use crate::puzzle_id;
+
+        #[test]
+        fn starts_at_midnight_on_the_first_of_december() {
+            assert_eq!(today_at(server_time(2023, 11, 30, 23, 59)), None);
+            assert_eq!(
+                today_at(server_time(2023, 12, 1, 0, 0)),
+                Some(puzzle_id!(2023, 1))
+            );
+        }
+
+        #[test]
+        fn ends_after_the_last_day_of_the_event() {
+            assert_eq!(
+                today_at(server_time(2024, 12, 25, 23, 59)),
+                Some(puzzle_id!(2024, 25))
+            );
+            assert_eq!(today_at(server_time(2024, 12, 26, 0, 0)), None);
+            assert_eq!(
+                today_at(server_time(2025, 12, 12, 23, 59)),
+                Some(puzzle_id!(2025, 12))
+            );
+            assert_eq!(today_at(server_time(2025, 12, 13, 0, 0)), None);
+            assert_eq!(today_at(server_time(2025, 1, 1, 0, 0)), None);
+        }
+
+        #[test]
+        fn uses_the_server_time_zone() {
+            let utc = FixedOffset::east_opt(0).unwrap();
+            // 04:59 UTC on the 1st of december is still the 30th of november on the server.
+            let before = utc.with_ymd_and_hms(2024, 12, 1, 4, 59, 0).unwrap();
+            assert_eq!(today_at(before), None);
+
+            let after = utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap();
+            assert_eq!(today_at(after), Some(puzzle_id!(2024, 1)));
+        }
+    }
+
+    mod next_unlock {
+        use super::{next_unlock_after, server_time};
+        use crate::puzzle_id;
+
+        #[test]
+        fn returns_a_puzzle_that_is_already_live() {
+            let (puzzle, unlock) = next_unlock_after(server_time(2024, 12, 3, 0, 5)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2024, 3));
+            assert_eq!(unlock, server_time(2024, 12, 3, 0, 0));
+
+            let (puzzle, unlock) = next_unlock_after(server_time(2024, 12, 25, 8, 0)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2024, 25));
+            assert_eq!(unlock, server_time(2024, 12, 25, 0, 0));
+        }
+
+        #[test]
+        fn waits_for_the_first_of_december() {
+            let (puzzle, unlock) = next_unlock_after(server_time(2024, 11, 30, 23, 59)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2024, 1));
+            assert_eq!(unlock, server_time(2024, 12, 1, 0, 0));
+
+            let (puzzle, unlock) = next_unlock_after(server_time(2024, 3, 1, 12, 0)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2024, 1));
+            assert_eq!(unlock, server_time(2024, 12, 1, 0, 0));
+        }
+
+        #[test]
+        fn waits_for_next_year_after_the_event() {
+            let (puzzle, unlock) = next_unlock_after(server_time(2024, 12, 26, 0, 0)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2025, 1));
+            assert_eq!(unlock, server_time(2025, 12, 1, 0, 0));
+
+            let (puzzle, unlock) = next_unlock_after(server_time(2024, 12, 31, 23, 59)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2025, 1));
+            assert_eq!(unlock, server_time(2025, 12, 1, 0, 0));
+        }
+
+        #[test]
+        fn waits_for_next_year_after_a_short_event() {
+            let (puzzle, unlock) = next_unlock_after(server_time(2025, 12, 13, 8, 0)).unwrap();
+            assert_eq!(puzzle, puzzle_id!(2026, 1));
+            assert_eq!(unlock, server_time(2026, 12, 1, 0, 0));
+        }
+    }
+
+    #[test]
+    fn parses_timestamps_and_dates() {
+        assert_eq!(
+            parse_now("2023-12-05T05:00:00Z"),
+            Some(server_time(2023, 12, 5, 0, 0))
+        );
+        assert_eq!(
+            parse_now("2023-12-04T23:59:00-05:00"),
+            Some(server_time(2023, 12, 4, 23, 59))
+        );
+        assert_eq!(
+            parse_now("2023-12-05"),
+            Some(server_time(2023, 12, 5, 0, 0))
+        );
+        assert_eq!(parse_now("december"), None);
+        assert_eq!(
+            today_at(parse_now("2015-12-24").unwrap()),
+            Some(puzzle_id!(2015, 24))
+        );
+    }
+}
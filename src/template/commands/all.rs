@@ -1,5 +1,9 @@
-use crate::template::{all_days, run_multi::run_multi};
+use crate::template::{all_days, run_multi::run_multi, PuzzleId, Year};
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+pub fn handle(year: Year, is_release: bool) {
+    run_multi(
+        &all_days(year).map(|day| PuzzleId::new(year, day)).collect(),
+        is_release,
+        false,
+    );
 }
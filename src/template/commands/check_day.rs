@@ -0,0 +1,193 @@
+use std::io::{stdout, Write};
+use std::path::Path;
+use std::process::{self, Command};
+
+use super::solve::cargo_args;
+use crate::template::day_check::{parse_answer, parse_tests, Status};
+#[cfg(feature = "encrypted-inputs")]
+use crate::template::encryption;
+use crate::template::submissions::Submissions;
+use crate::template::{
+    all_days, paths, Day, Part, PuzzleId, Year, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
+};
+
+/// Results of the checks of a part, with the counts of its example tests.
+struct PartCheck {
+    part: Part,
+    examples: Status,
+    tests: (usize, usize),
+    answer: Status,
+    input: Status,
+}
+
+/// Runs a cargo command and returns its standard output.
+fn cargo_output(args: &[String]) -> String {
+    match Command::new("cargo").args(args).output() {
+        Ok(output) => String::from_utf8_lossy(&output.stdout).into_owned(),
+        Err(e) => {
+            eprintln!("Failed to run cargo: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+fn has_input(puzzle: PuzzleId) -> bool {
+    let input_path = paths::input(puzzle);
+
+    #[cfg(feature = "encrypted-inputs")]
+    if Path::new(&encryption::get_encrypted_path(&input_path)).exists() {
+        return true;
+    }
+
+    Path::new(&input_path).exists()
+}
+
+/// Runs the example tests and the solution of a day and compares its answers to the recorded ones.
+/// Reasons for failed checks are added to `notes`.
+fn check(puzzle: PuzzleId, release: bool, notes: &mut Vec<String>) -> Vec<PartCheck> {
+    let test_output = cargo_output(&cargo_args("test", puzzle, false, false));
+    let built = test_output.contains("test result:");
+    if !built {
+        notes.push(format!(
+            "{puzzle}: failed to build, run `cargo test --bin {puzzle}` for details."
+        ));
+    }
+
+    // days without an input are shown with `-`.
+    let solution_output = has_input(puzzle).then(|| {
+        let mut args = cargo_args("run", puzzle, release, false);
+        args.push("--quiet".to_string());
+        cargo_output(&args)
+    });
+
+    let submissions = Submissions::read_or_warn(puzzle.year);
+
+    [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| puzzle.has_part(*part))
+        .map(|part| {
+            let tests = parse_tests(&test_output, part);
+            for name in &tests.failed {
+                notes.push(format!("{puzzle} part {part}: {name} failed."));
+            }
+
+            let answer = solution_output
+                .as_deref()
+                .map(|output| parse_answer(output, part));
+
+            let input = match &answer {
+                None => Status::Skip,
+                Some(Some(Some(_))) => Status::Pass,
+                Some(Some(None)) => Status::Fail,
+                Some(None) => {
+                    notes.push(format!("{puzzle} part {part}: the solution did not finish."));
+                    Status::Fail
+                }
+            };
+
+            let recorded = match (
+                submissions.correct_answer(puzzle.day, part),
+                answer.flatten().flatten(),
+            ) {
+                (None, _) => Status::Skip,
+                (Some(expected), Some(actual)) if expected == actual => Status::Pass,
+                (Some(expected), actual) => {
+                    notes.push(format!(
+                        "{puzzle} part {part}: answered {}, but {expected} was recorded as correct.",
+                        actual.as_deref().unwrap_or("nothing")
+                    ));
+                    Status::Fail
+                }
+            };
+
+            PartCheck {
+                part,
+                examples: if built { tests.status() } else { Status::Fail },
+                tests: (tests.passed, tests.passed + tests.failed.len()),
+                answer: recorded,
+                input,
+            }
+        })
+        .collect()
+}
+
+fn format_examples(check: &PartCheck) -> String {
+    match check.tests {
+        (_, 0) => check.examples.to_string(),
+        (passed, total) => format!("{} {passed}/{total}", check.examples),
+    }
+}
+
+/// Prints a pass / fail grid of the example tests, recorded answers and input runs of each part.
+/// Without a day, all scaffolded days of the year are checked.
+pub fn handle(year: Year, day: Option<Day>, release: bool) {
+    let puzzles: Vec<PuzzleId> = match day {
+        Some(day) => vec![PuzzleId::new(year, day)],
+        None => all_days(year).map(|day| PuzzleId::new(year, day)).collect(),
+    };
+
+    let puzzles: Vec<PuzzleId> = puzzles
+        .into_iter()
+        .filter(|puzzle| Path::new(&paths::bin(*puzzle)).exists())
+        .collect();
+
+    if puzzles.is_empty() {
+        match day {
+            Some(day) => eprintln!("Day {day} of {year} is not scaffolded."),
+            None => eprintln!("No scaffolded days found in {year}."),
+        }
+        process::exit(1);
+    }
+
+    println!(
+        "{ANSI_BOLD}{:<9}{:<6}{:<10}{:<8}Input{ANSI_RESET}",
+        "Day", "Part", "Examples", "Answer"
+    );
+
+    let mut notes = vec![];
+    let mut failed_days = 0;
+
+    for puzzle in &puzzles {
+        print!("{puzzle:<9}{ANSI_ITALIC}checking{ANSI_RESET}");
+        let _ = stdout().flush();
+
+        let checks = check(*puzzle, release, &mut notes);
+
+        for (index, check) in checks.iter().enumerate() {
+            let day = if index == 0 {
+                puzzle.to_string()
+            } else {
+                String::new()
+            };
+            println!(
+                "\r{day:<9}{:<6}{:<10}{:<8}{}",
+                check.part.to_string(),
+                format_examples(check),
+                check.answer.to_string(),
+                check.input
+            );
+        }
+
+        if checks
+            .iter()
+            .any(|c| [c.examples, c.answer, c.input].contains(&Status::Fail))
+        {
+            failed_days += 1;
+        }
+    }
+
+    if !notes.is_empty() {
+        println!();
+        for note in &notes {
+            println!("{note}");
+        }
+    }
+
+    println!("---");
+    if failed_days == 0 {
+        println!("✔ All {} day(s) pass.", puzzles.len());
+    } else {
+        println!("✖ {failed_days} of {} day(s) failed.", puzzles.len());
+        process::exit(1);
+    }
+}
@@ -1,14 +1,66 @@
-use crate::template::{aoc_cli, Day};
+#[cfg(not(feature = "client"))]
+use crate::template::aoc_cli;
+#[cfg(feature = "client")]
+use crate::template::aoc_client;
+use crate::template::commands::{extract_examples, scaffold};
+use crate::template::PuzzleId;
+#[cfg(feature = "encrypted-inputs")]
+use crate::template::{encryption, paths};
 use std::process;
 
-pub fn handle(day: Day) {
+/// Writes the encrypted copy of a downloaded input next to it.
+#[cfg(feature = "encrypted-inputs")]
+fn write_encrypted_input(puzzle: PuzzleId) {
+    let input_path = paths::input(puzzle);
+
+    let result = std::fs::read_to_string(&input_path)
+        .map_err(encryption::EncryptionError::from)
+        .and_then(|input| encryption::write_encrypted(&input_path, &input));
+
+    match result {
+        Ok(path) => println!("🎄 Successfully wrote encrypted input to \"{path}\"."),
+        Err(e) => {
+            eprintln!("failed to write encrypted input: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "client"))]
+pub fn handle(puzzle: PuzzleId) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::download(day) {
+    if let Err(e) = aoc_cli::download(puzzle) {
         eprintln!("failed to call aoc-cli: {e}");
+        if matches!(e, aoc_cli::AocCommandError::InvalidInput(_)) {
+            eprintln!("The previous input file was kept.");
+        }
         process::exit(1);
     };
+
+    #[cfg(feature = "encrypted-inputs")]
+    write_encrypted_input(puzzle);
+
+    extract_examples::handle(puzzle, false);
+    scaffold::refresh_header(puzzle);
+}
+
+#[cfg(feature = "client")]
+pub fn handle(puzzle: PuzzleId) {
+    if let Err(e) = aoc_client::download(puzzle) {
+        eprintln!("failed to download puzzle: {e}");
+        if matches!(e, aoc_client::AocClientError::InvalidInput(_)) {
+            eprintln!("The previous input file was kept.");
+        }
+        process::exit(1);
+    };
+
+    #[cfg(feature = "encrypted-inputs")]
+    write_encrypted_input(puzzle);
+
+    extract_examples::handle(puzzle, false);
+    scaffold::refresh_header(puzzle);
 }
@@ -0,0 +1,50 @@
+use std::{fs, process};
+
+use crate::template::backup::Backup;
+use crate::template::{example_tests, module_template, paths, PuzzleId};
+
+/// Replaces the tests of a solution with one test per example file of the day.
+/// Expected answers are formatted for the answer type of `part_one`, or the configured one if it cannot be read.
+pub fn handle(puzzle: PuzzleId) {
+    let PuzzleId { year, day } = puzzle;
+    let module_path = paths::bin(puzzle);
+
+    let Ok(source) = fs::read_to_string(&module_path) else {
+        eprintln!("No solution found at \"{module_path}\". Run `cargo scaffold {day} --year {year}` first.");
+        process::exit(1);
+    };
+
+    let examples = example_tests::find_examples(puzzle);
+    if examples.is_empty() {
+        eprintln!("No examples found for day {day} of {year}. Run `cargo extract-examples {day} --year {year}` first.");
+        process::exit(1);
+    }
+
+    let answer_type = example_tests::answer_type_of(&source)
+        .unwrap_or_else(|| module_template::answer_type(None));
+    let tests = example_tests::generate(puzzle, &examples, &source, &answer_type);
+    let Some(contents) = example_tests::replace_tests(&source, &tests) else {
+        eprintln!("Could not find the tests module of \"{module_path}\".");
+        process::exit(1);
+    };
+
+    match Backup::new().save(&module_path) {
+        Ok(Some(backup_path)) => println!("Backed up module file to \"{backup_path}\""),
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Failed to back up module file: {e}");
+            process::exit(1);
+        }
+    }
+
+    match fs::write(&module_path, contents) {
+        Ok(()) => println!(
+            "🎄 Updated the tests of \"{module_path}\" from {} example file(s).",
+            examples.len()
+        ),
+        Err(e) => {
+            eprintln!("Failed to write module file: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    fs,
+    io::{self, stdin, IsTerminal, Write},
+    process,
+};
+
+use crate::template::backup::Backup;
+use crate::template::{paths, puzzle, Part, PuzzleId, ANSI_ITALIC, ANSI_RESET};
+
+/// Number of lines shown per code block when asking which blocks are examples.
+const PREVIEW_LINES: usize = 3;
+
+/// Whether an example file has content that should not be replaced.
+fn has_content(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty())
+}
+
+/// Asks which code blocks to use as examples. Returns indices into `blocks`.
+/// Two blocks are the examples of part one and part two.
+fn select_blocks(blocks: &[String]) -> io::Result<Vec<usize>> {
+    for (i, block) in blocks.iter().enumerate() {
+        let lines: Vec<&str> = block.lines().collect();
+        println!("[{}] {} line(s)", i + 1, lines.len());
+        for line in lines.iter().take(PREVIEW_LINES) {
+            println!("    {line}");
+        }
+        if lines.len() > PREVIEW_LINES {
+            println!("    {ANSI_ITALIC}...{ANSI_RESET}");
+        }
+    }
+
+    print!("Which code blocks are examples? Enter a number, or two separated by a comma for the examples of part 1 and 2 (default: 1, 0 to skip): ");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    stdin().read_line(&mut answer)?;
+
+    if answer.trim().is_empty() {
+        return Ok(vec![0]);
+    }
+
+    Ok(answer
+        .split(',')
+        .filter_map(|x| x.trim().parse::<usize>().ok())
+        .filter(|x| (1..=blocks.len()).contains(x))
+        .map(|x| x - 1)
+        .collect())
+}
+
+/// Writes the code blocks of a downloaded puzzle description to the example files of a day.
+/// A single example is written to `NN.txt`, the examples of both parts to `NN-1.txt` and `NN-2.txt`,
+/// following the convention of [`read_file_part`](crate::template::read_file_part).
+pub fn handle(puzzle: PuzzleId, overwrite: bool) {
+    let PuzzleId { year, day } = puzzle;
+    let Some(markdown) = puzzle::read_puzzle(puzzle) else {
+        eprintln!("No puzzle description found for day {day} of {year}. Run `cargo download {day} --year {year}` first.");
+        process::exit(1);
+    };
+
+    let blocks = puzzle::code_blocks(&markdown);
+
+    let selected = match blocks.len() {
+        0 => {
+            println!("No code blocks found in the puzzle description.");
+            return;
+        }
+        1 => vec![0],
+        _ if stdin().is_terminal() => match select_blocks(&blocks) {
+            Ok(selected) => selected,
+            Err(e) => {
+                eprintln!("Failed to read selection: {e}");
+                process::exit(1);
+            }
+        },
+        _ => {
+            println!(
+                "Found {} code blocks, using the first one. Run `cargo extract-examples {day} --year {year}` to pick others.",
+                blocks.len()
+            );
+            vec![0]
+        }
+    };
+
+    // one example per part at most.
+    let parts = [Part::One, Part::Two]
+        .into_iter()
+        .filter(|part| puzzle.has_part(*part))
+        .count();
+    if selected.len() > parts {
+        println!(
+            "Selected {} code blocks, using the first {parts}, one per part.",
+            selected.len()
+        );
+    }
+    let selected = &selected[..selected.len().min(parts)];
+
+    let backup = Backup::new();
+
+    for (part, index) in (1..).zip(selected) {
+        let path = paths::example(puzzle, (selected.len() > 1).then_some(part));
+
+        if has_content(&path) {
+            if !overwrite {
+                println!("Skipped example file \"{path}\", it already has content. Use `--overwrite` to replace it.");
+                continue;
+            }
+
+            match backup.save(&path) {
+                Ok(Some(backup_path)) => println!("Backed up example file to \"{backup_path}\""),
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!("Failed to back up example file: {e}");
+                    process::exit(1);
+                }
+            }
+        }
+
+        match paths::create_parent_dir(&path).and_then(|()| fs::write(&path, &blocks[*index])) {
+            Ok(()) => println!("🎄 Successfully wrote example to \"{path}\"."),
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        }
+    }
+}
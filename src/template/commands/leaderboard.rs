@@ -0,0 +1,125 @@
+use std::{env, fs, process};
+
+#[cfg(feature = "client")]
+use crate::template::aoc_client;
+use crate::template::{
+    all_days,
+    durations::format_optional,
+    leaderboard::{Leaderboard, Member},
+    Day, Year, ANSI_BOLD, ANSI_RESET,
+};
+
+fn print_standings(standings: &[&Member], name_width: usize) {
+    println!("{ANSI_BOLD}Standings{ANSI_RESET}");
+    println!(
+        "  {:>3}  {:<name_width$}  {:>5}  {:>5}",
+        "#", "Name", "Score", "Stars"
+    );
+    for (i, member) in standings.iter().enumerate() {
+        println!(
+            "  {:>3}  {:<name_width$}  {:>5}  {:>5}",
+            i + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars
+        );
+    }
+}
+
+fn print_day(leaderboard: &Leaderboard, day: Day, name_width: usize) {
+    let mut rows: Vec<(String, Option<i64>, Option<i64>)> = leaderboard
+        .members
+        .iter()
+        .filter_map(|member| {
+            let (part_1, part_2) = leaderboard.completion_times(member.completion(day)?);
+            Some((member.display_name(), part_1, part_2))
+        })
+        .collect();
+
+    if rows.is_empty() {
+        return;
+    }
+
+    // members with both stars first, ordered by time.
+    rows.sort_by_key(|(_, part_1, part_2)| (part_2.unwrap_or(i64::MAX), *part_1));
+
+    println!();
+    println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+    println!(
+        "  {:<name_width$}  {:>9}  {:>9}  {:>9}",
+        "Name", "Part 1", "Part 2", "Delta"
+    );
+    for (name, part_1, part_2) in rows {
+        let delta = part_1.zip(part_2).map(|(a, b)| b - a);
+        println!(
+            "  {:<name_width$}  {:>9}  {:>9}  {:>9}",
+            name,
+            format_optional(part_1),
+            format_optional(part_2),
+            format_optional(delta)
+        );
+    }
+}
+
+fn get_leaderboard_id(id: Option<String>) -> Option<String> {
+    id.or_else(|| env::var("AOC_LEADERBOARD_ID").ok())
+}
+
+#[cfg(feature = "client")]
+fn fetch(year: Year, id: Option<String>) -> String {
+    let Some(id) = get_leaderboard_id(id) else {
+        eprintln!("No leaderboard id given. Pass `--id <id>` or set `AOC_LEADERBOARD_ID`.");
+        process::exit(1);
+    };
+
+    match aoc_client::leaderboard(year, &id) {
+        Ok(json) => json,
+        Err(e) => {
+            eprintln!("failed to fetch leaderboard: {e}");
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(not(feature = "client"))]
+fn fetch(year: Year, id: Option<String>) -> String {
+    let hint = get_leaderboard_id(id).map_or_else(String::new, |id| {
+        format!(" It can be downloaded from https://adventofcode.com/{year}/leaderboard/private/view/{id}.json.")
+    });
+    eprintln!("Fetching leaderboards requires the `client` feature. Pass `--file <path>` to read a downloaded leaderboard instead.{hint}");
+    process::exit(1);
+}
+
+/// Prints the standings of a private leaderboard and the star completion times of each day.
+/// The leaderboard JSON is read from `file` if given, otherwise fetched from the website for `year`.
+/// Days are listed for the event of the leaderboard, which can differ from `year` for a leaderboard read from a file.
+pub fn handle(year: Year, id: Option<String>, file: Option<String>, day: Option<Day>) {
+    let json = match file {
+        Some(path) => fs::read_to_string(&path).unwrap_or_else(|e| {
+            eprintln!("Failed to read leaderboard file \"{path}\": {e}");
+            process::exit(1);
+        }),
+        None => fetch(year, id),
+    };
+
+    let leaderboard = Leaderboard::try_from(json).unwrap_or_else(|e| {
+        eprintln!("Failed to parse leaderboard: {e}");
+        process::exit(1);
+    });
+
+    let standings = leaderboard.standings();
+    let name_width = standings
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or_default()
+        .max(4);
+
+    print_standings(&standings, name_width);
+
+    match day {
+        Some(day) => print_day(&leaderboard, day, name_width),
+        None => all_days(leaderboard.year().unwrap_or(year))
+            .for_each(|day| print_day(&leaderboard, day, name_width)),
+    }
+}
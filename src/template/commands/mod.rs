@@ -1,6 +1,14 @@
 pub mod all;
+pub mod check_day;
 pub mod download;
+pub mod examples;
+pub mod extract_examples;
+pub mod leaderboard;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stats;
 pub mod time;
+#[cfg(feature = "today")]
+pub mod today;
+pub mod unscaffold;
@@ -1,15 +1,62 @@
 use std::process;
 
-use crate::template::{aoc_cli, Day};
+#[cfg(not(feature = "client"))]
+use crate::template::aoc_cli;
+#[cfg(feature = "client")]
+use crate::template::aoc_client;
+use crate::template::{markdown, puzzle, Part, PuzzleId};
 
-pub fn handle(day: Day) {
+/// Renders the puzzle description of a day in the terminal.
+/// Reads the downloaded description and only fetches it if it is missing.
+pub fn handle(puzzle: PuzzleId, part: Option<Part>) {
+    match puzzle::read_puzzle(puzzle) {
+        Some(description) => print_puzzle(&description, part),
+        None => fetch(puzzle, part),
+    }
+}
+
+fn print_puzzle(description: &str, part: Option<Part>) {
+    let description = match part {
+        Some(part) => {
+            let Some(description) = puzzle::select_part(description, part) else {
+                eprintln!("Part {part} is not part of the puzzle description. Is part 1 solved and the puzzle downloaded again?");
+                process::exit(1);
+            };
+            description
+        }
+        None => description,
+    };
+
+    println!(
+        "{}",
+        markdown::render(description, markdown::terminal_width())
+    );
+}
+
+#[cfg(not(feature = "client"))]
+fn fetch(puzzle: PuzzleId, part: Option<Part>) {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
-    if let Err(e) = aoc_cli::read(day) {
+    if part.is_some() {
+        eprintln!("The puzzle has not been downloaded, showing all parts.");
+    }
+
+    if let Err(e) = aoc_cli::read(puzzle) {
         eprintln!("failed to call aoc-cli: {e}");
         process::exit(1);
     };
 }
+
+#[cfg(feature = "client")]
+fn fetch(puzzle: PuzzleId, part: Option<Part>) {
+    match aoc_client::read(puzzle) {
+        Ok(description) => print_puzzle(&description, part),
+        Err(e) => {
+            eprintln!("failed to read puzzle: {e}");
+            process::exit(1);
+        }
+    }
+}
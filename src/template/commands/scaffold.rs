@@ -1,13 +1,16 @@
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::Path,
     process,
 };
 
-use crate::template::Day;
-
-const MODULE_TEMPLATE: &str =
-    include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+use crate::template::backup::Backup;
+use crate::template::bench_target;
+use crate::template::example_tests;
+use crate::template::module_template::{self, Placeholders};
+use crate::template::solve_times::SolveTimes;
+use crate::template::{paths, puzzle, timestamp, PuzzleId};
 
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
@@ -19,18 +22,167 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
     file.truncate(true).write(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Creates an empty file, unless it already exists. Returns whether the file was created.
+fn create_empty_file(path: &str) -> Result<bool, std::io::Error> {
+    paths::create_parent_dir(path)?;
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Records when work on a day started, for `cargo stats`.
+fn record_start(puzzle: PuzzleId) {
+    let mut solve_times = match SolveTimes::read_from_file(puzzle.year) {
+        Ok(solve_times) => solve_times,
+        Err(e) => {
+            eprintln!(
+                "Not recording the start time of {puzzle}, {e}. Fix or remove the file first."
+            );
+            return;
+        }
+    };
+
+    if solve_times.record_start(puzzle.day, timestamp::now())
+        && solve_times.store_file(puzzle.year).is_err()
+    {
+        eprintln!("Failed to record the start time of {puzzle}.");
+    }
+}
+
+/// Title of the puzzle if its description was downloaded, e.g. "Guard Gallivant".
+fn downloaded_title(puzzle: PuzzleId) -> Option<String> {
+    puzzle::read_puzzle(puzzle).and_then(|markdown| puzzle::title(&markdown).map(str::to_string))
+}
+
+/// Inserts or refreshes the doc header of an existing solution, e.g. once its description was downloaded.
+pub fn refresh_header(puzzle: PuzzleId) {
+    let module_path = paths::bin(puzzle);
+    let Ok(source) = fs::read_to_string(&module_path) else {
+        return;
+    };
+
+    let header = module_template::header(puzzle, downloaded_title(puzzle).as_deref());
+    let contents = module_template::with_header(&source, &header);
+
+    if contents != source {
+        match fs::write(&module_path, contents) {
+            Ok(()) => println!("🎄 Updated the header of \"{module_path}\"."),
+            Err(e) => eprintln!("Failed to update the header of \"{module_path}\": {e}"),
+        }
+    }
+}
+
+/// Creates the bench of a day and declares it in `Cargo.toml`. An existing bench is kept.
+fn create_bench(puzzle: PuzzleId) {
+    let bench_path = paths::bench(puzzle);
+    let contents = bench_target::contents(puzzle, &bench_target::solution_path(puzzle));
+
+    match paths::create_parent_dir(&bench_path).and_then(|()| safe_create_file(&bench_path, false))
+    {
+        Ok(mut file) => match file.write_all(contents.as_bytes()) {
+            Ok(()) => println!("Created bench file \"{bench_path}\""),
+            Err(e) => {
+                eprintln!("Failed to write bench contents: {e}");
+                process::exit(1);
+            }
+        },
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            println!("Kept existing bench file \"{bench_path}\"");
+        }
+        Err(e) => {
+            eprintln!("Failed to create bench file: {e}");
+            process::exit(1);
+        }
+    }
+
+    let result = fs::read_to_string(bench_target::MANIFEST).and_then(|manifest| {
+        match bench_target::add_to_manifest(&manifest, puzzle) {
+            Some(manifest) => fs::write(bench_target::MANIFEST, manifest).map(|()| true),
+            None => Ok(false),
+        }
+    });
+
+    match result {
+        Ok(true) => println!(
+            "Declared bench \"{puzzle}\" in \"{}\"",
+            bench_target::MANIFEST
+        ),
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!(
+                "Failed to declare bench in \"{}\": {e}",
+                bench_target::MANIFEST
+            );
+            process::exit(1);
+        }
+    }
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(
+    puzzle: PuzzleId,
+    overwrite: bool,
+    bench: bool,
+    template: Option<&str>,
+    answer_type: Option<String>,
+) {
+    let input_path = paths::input(puzzle);
+    let example_path = paths::example(puzzle, None);
+    let module_path = paths::bin(puzzle);
+
+    // benches can be added to days that were scaffolded before.
+    if bench && !overwrite && Path::new(&module_path).exists() {
+        println!("Kept existing module file \"{module_path}\"");
+        create_bench(puzzle);
+        return;
+    }
+
+    let template = match module_template::load(template, puzzle) {
+        Ok(template) => template,
+        Err(e) => {
+            eprintln!("Failed to read template: {e}");
+            process::exit(1);
+        }
+    };
+
+    let title = downloaded_title(puzzle);
+    let answer_type = module_template::answer_type(answer_type);
+    let mut contents = module_template::render(
+        &template,
+        &Placeholders {
+            puzzle,
+            title: &title
+                .clone()
+                .unwrap_or_else(|| format!("Day {}", puzzle.day.into_inner())),
+            answer_type: &answer_type,
+        },
+    );
+
+    // examples might already be extracted, e.g. when scaffolding again with `--overwrite`.
+    let examples = example_tests::find_examples(puzzle);
+    if !examples.is_empty() {
+        let tests = example_tests::generate(puzzle, &examples, &contents, &answer_type);
+        if let Some(with_tests) = example_tests::replace_tests(&contents, &tests) {
+            contents = with_tests;
+        }
+    }
+
+    contents = module_template::with_header(
+        &contents,
+        &module_template::header(puzzle, title.as_deref()),
+    );
+
+    if overwrite {
+        match Backup::new().save(&module_path) {
+            Ok(Some(backup_path)) => println!("Backed up module file to \"{backup_path}\""),
+            Ok(None) => {}
+            Err(e) => {
+                eprintln!("Failed to back up module file: {e}");
+                process::exit(1);
+            }
+        }
+    }
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -40,11 +192,7 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(contents.as_bytes()) {
         Ok(()) => {
             println!("Created module file \"{}\"", &module_path);
         }
@@ -54,26 +202,42 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
+    // data files are never overwritten, they might hold a downloaded input or example.
+    match create_empty_file(&input_path) {
+        Ok(true) => {
             println!("Created empty input file \"{}\"", &input_path);
         }
+        Ok(false) => {
+            println!("Kept existing input file \"{}\"", &input_path);
+        }
         Err(e) => {
             eprintln!("Failed to create input file: {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
+    match create_empty_file(&example_path) {
+        Ok(true) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
+        Ok(false) => {
+            println!("Kept existing example file \"{}\"", &example_path);
+        }
         Err(e) => {
             eprintln!("Failed to create example file: {e}");
             process::exit(1);
         }
     }
 
+    if bench {
+        create_bench(puzzle);
+    }
+
+    record_start(puzzle);
+
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day, puzzle.year
+    );
 }
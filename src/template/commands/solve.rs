@@ -1,34 +1,257 @@
-use std::process::{Command, Stdio};
+use std::path::PathBuf;
+use std::process::{self, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
-use crate::template::Day;
+use crate::template::watch::Watcher;
+use crate::template::{paths, Part, PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Clears the terminal and moves the cursor to the top left.
+const ANSI_CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// How often watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Editors might write a file in several steps, a change is handled once files were quiet for this long.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+/// Arguments of a cargo command for the binary of a puzzle, e.g. `run --bin 2024-01 --release`.
+pub(super) fn cargo_args(
+    command: &str,
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+) -> Vec<String> {
+    let mut cmd_args = vec![command.to_string(), "--bin".to_string(), puzzle.to_string()];
+    let mut features = vec![];
 
     if dhat {
-        cmd_args.extend([
-            "--profile".to_string(),
-            "dhat".to_string(),
-            "--features".to_string(),
-            "dhat-heap".to_string(),
-        ]);
+        cmd_args.extend(["--profile".to_string(), "dhat".to_string()]);
+        features.push("dhat-heap");
     } else if release {
         cmd_args.push("--release".to_string());
     }
 
+    if cfg!(feature = "client") {
+        // mirror the `client` feature so the solution submits through the built-in client.
+        features.push("client");
+    }
+
+    if cfg!(feature = "encrypted-inputs") {
+        // mirror the `encrypted-inputs` feature so the solution can read encrypted inputs.
+        features.push("encrypted-inputs");
+    }
+
+    if !features.is_empty() {
+        cmd_args.extend(["--features".to_string(), features.join(",")]);
+    }
+
+    cmd_args
+}
+
+fn cargo(args: &[String]) -> ExitStatus {
+    Command::new("cargo")
+        .args(args)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .status()
+        .unwrap()
+}
+
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<Part>,
+    wait: bool,
+    watch: bool,
+) {
+    if wait && submit_part.is_none() {
+        eprintln!("`--wait` can only be combined with `--submit`.");
+        process::exit(1);
+    }
+
+    if watch {
+        if submit_part.is_some() {
+            eprintln!("`--watch` can not be combined with `--submit`.");
+            process::exit(1);
+        }
+        watch_day(puzzle, release, dhat);
+    }
+
+    let mut cmd_args = cargo_args("run", puzzle, release, dhat);
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
+        if !puzzle.has_part(submit_part) {
+            eprintln!("Day {} has no part {submit_part}.", puzzle.day);
+            process::exit(1);
+        }
+
         cmd_args.push("--submit".to_string());
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
-        .args(&cmd_args)
-        .stdout(Stdio::inherit())
-        .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+    if wait {
+        cmd_args.push("--wait".to_string());
+    }
+
+    cargo(&cmd_args);
+}
+
+/// Builds with `build_args`, then runs `run_args`. Returns whether both succeeded.
+fn build_and_run(label: &str, build_args: &[String], run_args: &[String]) -> bool {
+    let timer = Instant::now();
+    let built = cargo(build_args).success();
+    let elapsed = timer.elapsed();
+
+    if !built {
+        println!("{ANSI_BOLD}✖ Failed to build {label}{ANSI_RESET} ({elapsed:.1?})");
+        return false;
+    }
 
-    cmd.wait().unwrap();
+    println!("{ANSI_ITALIC}Rebuilt {label} in {elapsed:.1?}{ANSI_RESET}");
+    cargo(run_args).success()
+}
+
+/// Runs the example tests and the solution of a day.
+fn run_once(puzzle: PuzzleId, release: bool, dhat: bool) {
+    let quiet = |mut args: Vec<String>| {
+        args.push("--quiet".to_string());
+        args
+    };
+
+    let mut test_build = quiet(cargo_args("test", puzzle, false, false));
+    test_build.push("--no-run".to_string());
+
+    let tests_passed = build_and_run(
+        "tests",
+        &test_build,
+        &quiet(cargo_args("test", puzzle, false, false)),
+    );
+
+    println!();
+
+    let solved = build_and_run(
+        "solution",
+        &quiet(cargo_args("build", puzzle, release, dhat)),
+        &quiet(cargo_args("run", puzzle, release, dhat)),
+    );
+
+    println!("---");
+    println!(
+        "{} Example tests, {} solution",
+        if tests_passed { "✔" } else { "✖" },
+        if solved { "✔" } else { "✖" }
+    );
+}
+
+/// Runs the example tests and the solution of a day whenever its files change.
+/// Watches the solution, the library and the data files of the day.
+fn watch_day(puzzle: PuzzleId, release: bool, dhat: bool) -> ! {
+    let mut watched = vec![
+        paths::bin(puzzle),
+        "./src/lib.rs".to_string(),
+        "./src/template".to_string(),
+    ];
+    watched.extend(paths::day_data_files(puzzle));
+
+    let mut watcher = Watcher::new(&watched);
+    let mut changes: Vec<PathBuf> = vec![];
+
+    loop {
+        print!("{ANSI_CLEAR}");
+        println!(
+            "{ANSI_BOLD}Watching day {} ({}){ANSI_RESET}",
+            puzzle.day, puzzle.year
+        );
+        for path in &changes {
+            println!("{ANSI_ITALIC}Changed {}{ANSI_RESET}", path.display());
+        }
+        println!();
+
+        run_once(puzzle, release, dhat);
+        println!("{ANSI_ITALIC}Waiting for changes, press Ctrl+C to stop.{ANSI_RESET}");
+
+        changes = loop {
+            thread::sleep(POLL_INTERVAL);
+            let mut changes = watcher.changes();
+            if changes.is_empty() {
+                continue;
+            }
+
+            loop {
+                thread::sleep(SETTLE_TIME);
+                let more = watcher.changes();
+                if more.is_empty() {
+                    break;
+                }
+                changes.extend(more);
+            }
+
+            changes.sort_unstable();
+            changes.dedup();
+            break changes;
+        };
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::cargo_args;
+    use crate::puzzle_id;
+
+    /// Features of this build that are forwarded to the solution.
+    fn forwarded() -> Vec<&'static str> {
+        [
+            (cfg!(feature = "client"), "client"),
+            (cfg!(feature = "encrypted-inputs"), "encrypted-inputs"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, feature)| enabled.then_some(feature))
+        .collect()
+    }
+
+    #[test]
+    fn forwards_enabled_features() {
+        let mut expected = vec!["run", "--bin", "2024-01", "--release"];
+        let features = forwarded().join(",");
+        if !features.is_empty() {
+            expected.extend(["--features", &features]);
+        }
+
+        assert_eq!(
+            cargo_args("run", puzzle_id!(2024, 1), true, false),
+            expected
+        );
+    }
+
+    #[test]
+    fn combines_dhat_with_forwarded_features() {
+        let features = [vec!["dhat-heap"], forwarded()].concat().join(",");
+        assert_eq!(
+            cargo_args("test", puzzle_id!(2024, 1), true, true),
+            vec![
+                "test",
+                "--bin",
+                "2024-01",
+                "--profile",
+                "dhat",
+                "--features",
+                &features
+            ]
+        );
+    }
+
+    #[cfg(feature = "encrypted-inputs")]
+    #[test]
+    fn forwards_encrypted_inputs() {
+        let args = cargo_args("run", puzzle_id!(2024, 1), false, false);
+        let features = &args[args.iter().position(|arg| arg == "--features").unwrap() + 1];
+        assert!(features
+            .split(',')
+            .any(|feature| feature == "encrypted-inputs"));
+    }
 }
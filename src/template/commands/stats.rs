@@ -0,0 +1,30 @@
+use crate::template::durations::format_optional;
+use crate::template::solve_times;
+use crate::template::{Year, ANSI_BOLD, ANSI_RESET};
+
+/// Prints how long it took to solve each part of a year, counted from when the day was scaffolded or unlocked.
+pub fn handle(year: Year) {
+    let stats = solve_times::read_stats(year);
+
+    if stats.is_empty() {
+        println!("No solved days in {year} yet. Solve times are recorded when submitting with `cargo solve <day> --submit <part>`.");
+        return;
+    }
+
+    println!("{ANSI_BOLD}{year}{ANSI_RESET}");
+    println!(
+        "{ANSI_BOLD}{:<3}  {:>9}  {:>9}  {:>9}{ANSI_RESET}",
+        "Day", "Part 1", "Part 2", "Delta"
+    );
+
+    for day in stats {
+        let delta = day.part_1.zip(day.part_2).map(|(a, b)| b - a);
+        println!(
+            "{:<3}  {:>9}  {:>9}  {:>9}",
+            day.puzzle.day.to_string(),
+            format_optional(day.part_1),
+            format_optional(day.part_2),
+            format_optional(delta)
+        );
+    }
+}
@@ -2,33 +2,34 @@ use std::collections::HashSet;
 
 use crate::template::run_multi::run_multi;
 use crate::template::timings::Timings;
-use crate::template::{all_days, readme_benchmarks, Day};
+use crate::template::{all_days, readme_benchmarks, Day, PuzzleId, Year};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+pub fn handle(year: Year, day: Option<Day>, run_all: bool, store: bool) {
     let stored_timings = Timings::read_from_file();
+    let puzzles = all_days(year).map(|day| PuzzleId::new(year, day));
 
-    let days_to_run = day.map_or_else(
+    let puzzles_to_run = day.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                puzzles.collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                puzzles
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |day| HashSet::from([PuzzleId::new(year, day)]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&puzzles_to_run, true, true).unwrap();
 
     if store {
         let merged_timings = stored_timings.merge(&timings);
         merged_timings.store_file().unwrap();
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(&merged_timings) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
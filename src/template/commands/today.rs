@@ -0,0 +1,78 @@
+use std::{
+    io::{stdout, Write},
+    process, thread,
+    time::{Duration, Instant},
+};
+
+use crate::template::commands::{download, read, scaffold};
+use crate::template::{clock, PuzzleId, ANSI_BOLD, ANSI_RESET};
+
+/// Time to wait after the unlock before downloading, to not hit the website before the puzzle is live.
+const UNLOCK_GRACE: Duration = Duration::from_secs(2);
+
+fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs();
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
+
+/// Show a countdown until the next puzzle unlocks and return it.
+/// A puzzle that is already live and a clock set with `AOC_NOW`, which stands still, return right away.
+fn wait_for_unlock() -> PuzzleId {
+    let Some((puzzle, remaining)) = PuzzleId::next_unlock() else {
+        eprintln!("Could not determine the next unlock time.");
+        process::exit(1);
+    };
+
+    if remaining <= chrono::Duration::zero() {
+        println!("🎄 Day {} is already unlocked!", puzzle.day);
+        return puzzle;
+    }
+
+    if clock::is_overridden() {
+        println!(
+            "🎄 Day {} unlocks in {ANSI_BOLD}{}{ANSI_RESET}, skipping the wait as `AOC_NOW` is set.",
+            puzzle.day,
+            format_countdown(remaining.to_std().unwrap_or_default())
+        );
+        return puzzle;
+    }
+
+    let mut stdout = stdout();
+    let deadline = Instant::now() + remaining.to_std().unwrap_or_default() + UNLOCK_GRACE;
+
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        print!(
+            "\r🎄 Day {} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            puzzle.day,
+            format_countdown(remaining)
+        );
+        let _ = stdout.flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\r🎄 Day {} is unlocked!              ", puzzle.day);
+    puzzle
+}
+
+pub fn handle(wait: bool) {
+    let puzzle = if wait {
+        wait_for_unlock()
+    } else {
+        match PuzzleId::today() {
+            Some(puzzle) => puzzle,
+            None => {
+                eprintln!(
+                    "`today` command can only be run during the event, which starts \
+                    on the 1st of december. Please use `scaffold` with a specific day, \
+                    pass `--wait` to wait for the next puzzle or set `AOC_NOW` to a date \
+                    of a past event."
+                );
+                process::exit(1)
+            }
+        }
+    };
+
+    scaffold::handle(puzzle, false, false, None, None);
+    download::handle(puzzle);
+    read::handle(puzzle, None);
+}
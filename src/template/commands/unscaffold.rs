@@ -0,0 +1,109 @@
+use std::{
+    fs,
+    io::{self, stdin, IsTerminal, Write},
+    path::Path,
+    process,
+};
+
+use crate::template::backup::Backup;
+use crate::template::bench_target;
+#[cfg(feature = "encrypted-inputs")]
+use crate::template::encryption;
+use crate::template::{paths, PuzzleId};
+
+/// All files of a day that exist: the solution and its bench, input, examples and puzzle description.
+fn day_files(puzzle: PuzzleId) -> Vec<String> {
+    let mut files = vec![paths::bin(puzzle), paths::bench(puzzle)];
+    files.extend(paths::day_data_files(puzzle));
+
+    #[cfg(feature = "encrypted-inputs")]
+    files.push(encryption::get_encrypted_path(&paths::input(puzzle)));
+
+    files
+        .into_iter()
+        .filter(|path| Path::new(path).is_file())
+        .collect()
+}
+
+fn confirm() -> io::Result<bool> {
+    print!("Remove these files? [y/N] ");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    stdin().read_line(&mut answer)?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+/// Removes the `[[bench]]` entry of a day, cargo fails to build if a declared bench is missing.
+fn remove_bench_from_manifest(puzzle: PuzzleId, backup: &Backup) {
+    let Ok(manifest) = fs::read_to_string(bench_target::MANIFEST) else {
+        return;
+    };
+
+    let Some(manifest) = bench_target::remove_from_manifest(&manifest, puzzle) else {
+        return;
+    };
+
+    let result = backup
+        .save(bench_target::MANIFEST)
+        .and_then(|_| fs::write(bench_target::MANIFEST, manifest));
+
+    if let Err(e) = result {
+        eprintln!(
+            "Failed to remove bench \"{puzzle}\" from \"{}\": {e}",
+            bench_target::MANIFEST
+        );
+        process::exit(1);
+    }
+}
+
+/// Removes the files of a day after backing them up. Submissions, timings and solve times are kept.
+pub fn handle(puzzle: PuzzleId, yes: bool) {
+    let PuzzleId { year, day } = puzzle;
+    let files = day_files(puzzle);
+
+    if files.is_empty() {
+        println!("No files found for day {day} of {year}.");
+        return;
+    }
+
+    for path in &files {
+        println!("  {path}");
+    }
+
+    if !yes {
+        if !stdin().is_terminal() {
+            eprintln!("Refusing to remove files without confirmation. Pass `--yes` to remove them anyway.");
+            process::exit(1);
+        }
+
+        match confirm() {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("Kept all files.");
+                return;
+            }
+            Err(e) => {
+                eprintln!("Failed to read confirmation: {e}");
+                process::exit(1);
+            }
+        }
+    }
+
+    let backup = Backup::new();
+
+    for path in &files {
+        if let Err(e) = backup.save(path).and_then(|_| fs::remove_file(path)) {
+            eprintln!("Failed to remove \"{path}\": {e}");
+            process::exit(1);
+        }
+    }
+
+    remove_bench_from_manifest(puzzle, &backup);
+
+    println!(
+        "🎄 Removed {} file(s), a backup was written to \"{}\".",
+        files.len(),
+        backup.dir().display()
+    );
+}
@@ -3,18 +3,23 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use crate::template::PuzzleId;
+use crate::template::Year;
 
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+/// Puzzles unlock at midnight in this time zone.
+pub(crate) const SERVER_UTC_OFFSET: i32 = -5;
 
-/// A valid day number of advent (i.e. an integer in range 1 to 25).
+/// A day number of advent, i.e. an integer in range 1 to 25, the length of the longest events.
+///
+/// A [`Day`] does not know its year, so it is only checked against that bound.
+/// Not every event lasts 25 days, see [`Year::event_length`]. Use [`PuzzleId::try_new`](crate::template::PuzzleId::try_new)
+/// to check that a day is part of the event of a year, as the commands do for the days they are given.
 ///
 /// # Display
 /// This value displays as a two digit number.
 ///
 /// ```
-/// # use advent_of_code::Day;
+/// # use advent_of_code::template::Day;
 /// let day = Day::new(8).unwrap();
 /// assert_eq!(day.to_string(), "08")
 /// ```
@@ -22,8 +27,8 @@ const SERVER_UTC_OFFSET: i32 = -5;
 pub struct Day(u8);
 
 impl Day {
-    /// Creates a [`Day`] from the provided value if it's in the valid range,
-    /// returns [`None`] otherwise.
+    /// Creates a [`Day`] from the provided value if it's in range 1 to 25,
+    /// returns [`None`] otherwise. Shorter events are not taken into account.
     pub fn new(day: u8) -> Option<Self> {
         if day == 0 || day > 25 {
             return None;
@@ -41,19 +46,56 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns the time the puzzle of this day unlocks in the given year, as seconds since the unix epoch.
+    pub fn unlock_timestamp(self, year: i64) -> i64 {
+        days_from_civil(year, 12, i64::from(self.0)) * 86400 - i64::from(SERVER_UTC_OFFSET) * 3600
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic gregorian calendar.
+/// See: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Date in the proleptic gregorian calendar of a number of days since the unix epoch, as `(year, month, day)`.
+/// See: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Returns the calendar year of a time in the server time zone, given as seconds since the unix epoch.
+pub(crate) fn server_year(timestamp: i64) -> i64 {
+    let days = (timestamp + i64::from(SERVER_UTC_OFFSET) * 3600).div_euclid(86400);
+    civil_from_days(days).0
 }
 
 #[cfg(feature = "today")]
 impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
+    /// Returns the current day if it's a day of this year's event in december, `None` otherwise.
+    /// See [`PuzzleId::today`] for how the current time is determined.
     pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
+        PuzzleId::today().map(|puzzle| puzzle.day)
     }
 }
 
@@ -94,26 +136,29 @@ impl Error for DayFromStrError {}
 
 impl Display for DayFromStrError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_str("expecting a day number between 1 and 25")
+        f.write_str("expecting a day number between 1 and 25, the length of the longest events")
     }
 }
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of the event of a year, from the 1st to its last day.
+pub fn all_days(year: Year) -> AllDays {
+    AllDays::new(year)
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of the event of a year, from the 1st to its last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    pub fn new(year: Year) -> Self {
+        Self {
+            current: 1,
+            last: year.event_length(),
+        }
     }
 }
 
@@ -121,10 +166,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and event lengths are never above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -135,6 +180,7 @@ impl Iterator for AllDays {
 /* -------------------------------------------------------------------------- */
 
 /// Creates a [`Day`] value in a const context.
+/// Like [`Day::new`], only days beyond 25 are rejected, not days beyond the end of a shorter event.
 #[macro_export]
 macro_rules! day {
     ($day:expr) => {{
@@ -143,7 +189,7 @@ macro_rules! day {
             concat!(
                 "invalid day number `",
                 $day,
-                "`, expecting a value between 1 and 25"
+                "`, expecting a value between 1 and 25, the length of the longest events"
             ),
         );
         $crate::template::Day::__new_unchecked($day)
@@ -154,11 +200,39 @@ macro_rules! day {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, civil_from_days, days_from_civil, server_year, Day};
+    use crate::year;
+
+    #[test]
+    fn unlock_timestamps() {
+        // 2024-12-01T05:00:00Z
+        assert_eq!(Day(1).unlock_timestamp(2024), 1_733_029_200);
+        // 2023-12-25T05:00:00Z
+        assert_eq!(Day(25).unlock_timestamp(2023), 1_703_480_400);
+    }
+
+    #[test]
+    fn converts_days_to_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        for (year, month, day) in [(2000, 2, 29), (2024, 12, 1), (2024, 12, 31), (2100, 3, 1)] {
+            assert_eq!(
+                civil_from_days(days_from_civil(year, month, day)),
+                (year, month, day)
+            );
+        }
+    }
+
+    #[test]
+    fn server_years() {
+        // 2025-01-01T04:59:59Z is still 2024 on the server.
+        assert_eq!(server_year(1_735_707_599), 2024);
+        assert_eq!(server_year(1_735_707_600), 2025);
+    }
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
+        let mut iter = all_days(year!(2024));
 
         assert_eq!(iter.next(), Some(Day(1)));
         assert_eq!(iter.next(), Some(Day(2)));
@@ -187,6 +261,12 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_of_short_events() {
+        assert_eq!(all_days(year!(2025)).last(), Some(Day(12)));
+        assert_eq!(all_days(year!(2025)).count(), 12);
+    }
 }
 
 /* -------------------------------------------------------------------------- */
@@ -0,0 +1,182 @@
+/// Checks a day for `cargo check-day`: its example tests, a run against its input and
+/// whether the answers of that run match the correct answers recorded by `cargo solve --submit`.
+///
+/// Results are parsed from the output of `cargo test` and of the solution binary.
+use std::fmt::Display;
+
+use crate::template::example_tests::function_name;
+use crate::template::Part;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Skip,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "✔"),
+            Status::Fail => write!(f, "✖"),
+            Status::Skip => write!(f, "-"),
+        }
+    }
+}
+
+/// Tests of a part, e.g. `test_part_one` and hand-written tests like `test_part_one_edge_cases`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PartTests {
+    pub passed: usize,
+    pub failed: Vec<String>,
+}
+
+impl PartTests {
+    pub fn status(&self) -> Status {
+        if !self.failed.is_empty() {
+            Status::Fail
+        } else if self.passed > 0 {
+            Status::Pass
+        } else {
+            Status::Skip
+        }
+    }
+}
+
+/// Parses the results of the tests of a part from the output of `cargo test`,
+/// e.g. `test tests::test_part_one ... ok`.
+pub fn parse_tests(output: &str, part: Part) -> PartTests {
+    let mut tests = PartTests::default();
+
+    for line in output.lines() {
+        let Some((name, result)) = line
+            .strip_prefix("test ")
+            .and_then(|line| line.split_once(" ... "))
+        else {
+            continue;
+        };
+
+        if !name.contains(function_name(part)) {
+            continue;
+        }
+
+        match result.trim() {
+            "ok" => tests.passed += 1,
+            "FAILED" => tests.failed.push(name.to_string()),
+            _ => {}
+        }
+    }
+
+    tests
+}
+
+/// Parses the answer of a part from the output of a solution, e.g. `Part 1: 42 (166.0ns)`.
+/// Returns `Some(None)` if the part returned no answer, `None` if the part did not run.
+pub fn parse_answer(output: &str, part: Part) -> Option<Option<String>> {
+    let prefix = format!("Part {part}: ");
+    let lines: Vec<String> = output
+        .lines()
+        // results are printed twice on one line, separated by a carriage return.
+        .map(|line| strip_ansi(line.rsplit('\r').next().unwrap_or_default()))
+        .collect();
+
+    let index = lines.iter().position(|line| line.starts_with(&prefix))?;
+    let result = lines[index][prefix.len()..].trim();
+
+    if result.starts_with('✖') {
+        return Some(None);
+    }
+
+    // multi-line results are printed below the part.
+    if result.starts_with('▼') {
+        let answer: Vec<&str> = lines[index + 1..]
+            .iter()
+            .take_while(|line| !line.starts_with("Part "))
+            .map(String::as_str)
+            .collect();
+        return Some(Some(answer.join("\n")));
+    }
+
+    let answer = match result.rfind(" (") {
+        Some(end) => &result[..end],
+        None => result,
+    };
+
+    Some(Some(answer.to_string()))
+}
+
+fn strip_ansi(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip the escape sequence up to its final letter, e.g. `\x1b[1m`.
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{parse_answer, parse_tests, PartTests, Status};
+    use crate::part;
+
+    const TEST_OUTPUT: &str = "
+running 4 tests
+test tests::test_part_one ... ok
+test tests::test_part_two ... FAILED
+test tests::test_part_two_edge_cases ... ok
+test tests::test_parse ... ok
+
+test result: FAILED. 3 passed; 1 failed; 0 ignored; 0 measured; 0 filtered out; finished in 0.00s
+";
+
+    #[test]
+    fn parses_tests_per_part() {
+        let tests = parse_tests(TEST_OUTPUT, part!(1));
+        assert_eq!(
+            tests,
+            PartTests {
+                passed: 1,
+                failed: vec![]
+            }
+        );
+        assert_eq!(tests.status(), Status::Pass);
+
+        let tests = parse_tests(TEST_OUTPUT, part!(2));
+        assert_eq!(tests.passed, 1);
+        assert_eq!(tests.failed, vec!["tests::test_part_two".to_string()]);
+        assert_eq!(tests.status(), Status::Fail);
+
+        assert_eq!(parse_tests("", part!(1)).status(), Status::Skip);
+    }
+
+    #[test]
+    fn parses_answers_of_solutions() {
+        let output = "Part 1: \x1b[1m143\x1b[0m\rPart 1: \x1b[1m143\x1b[0m (70.2µs)\nPart 2: ✖\rPart 2: ✖             \n";
+        assert_eq!(parse_answer(output, part!(1)), Some(Some("143".into())));
+        assert_eq!(parse_answer(output, part!(2)), Some(None));
+        assert_eq!(parse_answer("", part!(1)), None);
+    }
+
+    #[test]
+    fn parses_multi_line_answers() {
+        let output = "Part 1: ▼ \rPart 1: ▼  (1.0ms)\n#..#\n.##.\nPart 2: 4 (1.0ms)\n";
+        assert_eq!(
+            parse_answer(output, part!(1)),
+            Some(Some("#..#\n.##.".into()))
+        );
+        assert_eq!(parse_answer(output, part!(2)), Some(Some("4".into())));
+    }
+}
@@ -0,0 +1,33 @@
+/// Formats a number of seconds as `HH:MM:SS`. Hours are not wrapped at a day.
+pub fn format_elapsed(seconds: i64) -> String {
+    let sign = if seconds < 0 { "-" } else { "" };
+    let seconds = seconds.abs();
+    format!(
+        "{sign}{:02}:{:02}:{:02}",
+        seconds / 3600,
+        (seconds / 60) % 60,
+        seconds % 60
+    )
+}
+
+/// Formats a number of seconds like [`format_elapsed`], or `-` if there is none.
+pub fn format_optional(seconds: Option<i64>) -> String {
+    seconds.map_or_else(|| "-".into(), format_elapsed)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_elapsed, format_optional};
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_elapsed(0), "00:00:00");
+        assert_eq!(format_elapsed(3723), "01:02:03");
+        assert_eq!(format_elapsed(100_000), "27:46:40");
+        assert_eq!(format_elapsed(-61), "-00:01:01");
+        assert_eq!(format_optional(Some(3723)), "01:02:03");
+        assert_eq!(format_optional(None), "-");
+    }
+}
@@ -0,0 +1,161 @@
+/// Encrypted copies of puzzle inputs that can be committed to a public repository.
+///
+/// Files are encrypted with ChaCha20-Poly1305. The key is the SHA-256 hash of the secret in `AOC_INPUT_KEY`.
+/// An encrypted file consists of a random nonce followed by the ciphertext.
+use std::{env, fmt::Display, fs, io};
+
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN},
+    digest::{digest, SHA256},
+    rand::{SecureRandom, SystemRandom},
+};
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    KeyNotFound,
+    Malformed,
+    WrongKey,
+    Random,
+    IO(io::Error),
+}
+
+impl Display for EncryptionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EncryptionError::KeyNotFound => write!(f, "`AOC_INPUT_KEY` is not set."),
+            EncryptionError::Malformed => write!(f, "the encrypted file is malformed."),
+            EncryptionError::WrongKey => write!(
+                f,
+                "the encrypted file could not be decrypted, `AOC_INPUT_KEY` might be wrong."
+            ),
+            EncryptionError::Random => write!(f, "could not generate a nonce."),
+            EncryptionError::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for EncryptionError {
+    fn from(e: io::Error) -> Self {
+        EncryptionError::IO(e)
+    }
+}
+
+fn get_secret() -> Result<String, EncryptionError> {
+    env::var("AOC_INPUT_KEY")
+        .ok()
+        .filter(|x| !x.trim().is_empty())
+        .ok_or(EncryptionError::KeyNotFound)
+}
+
+fn get_key(secret: &str) -> LessSafeKey {
+    let hash = digest(&SHA256, secret.trim().as_bytes());
+    // a SHA-256 hash always has the key length of ChaCha20.
+    LessSafeKey::new(UnboundKey::new(&CHACHA20_POLY1305, hash.as_ref()).unwrap())
+}
+
+/// Path of the encrypted copy of a file.
+#[must_use]
+pub fn get_encrypted_path(path: &str) -> String {
+    format!("{path}.enc")
+}
+
+pub fn encrypt(plain: &str, secret: &str) -> Result<Vec<u8>, EncryptionError> {
+    let mut nonce = [0; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| EncryptionError::Random)?;
+
+    let mut data = plain.as_bytes().to_vec();
+    get_key(secret)
+        .seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| EncryptionError::Malformed)?;
+
+    Ok([nonce.as_slice(), &data].concat())
+}
+
+pub fn decrypt(encrypted: &[u8], secret: &str) -> Result<String, EncryptionError> {
+    if encrypted.len() < NONCE_LEN {
+        return Err(EncryptionError::Malformed);
+    }
+
+    let (nonce, data) = encrypted.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| EncryptionError::Malformed)?;
+
+    let mut data = data.to_vec();
+    let plain = get_key(secret)
+        .open_in_place(nonce, Aad::empty(), &mut data)
+        .map_err(|_| EncryptionError::WrongKey)?;
+
+    String::from_utf8(plain.to_vec()).map_err(|_| EncryptionError::Malformed)
+}
+
+/// Reads and decrypts the encrypted copy of a file.
+pub fn read_encrypted(path: &str) -> Result<String, EncryptionError> {
+    let secret = get_secret()?;
+    decrypt(&fs::read(get_encrypted_path(path))?, &secret)
+}
+
+/// Writes the encrypted copy of a file.
+/// An existing copy is kept if it has the same content, as every encryption produces different bytes.
+pub fn write_encrypted(path: &str, plain: &str) -> Result<String, EncryptionError> {
+    let secret = get_secret()?;
+    let encrypted_path = get_encrypted_path(path);
+
+    let is_unchanged = fs::read(&encrypted_path)
+        .ok()
+        .and_then(|x| decrypt(&x, &secret).ok())
+        .is_some_and(|x| x == plain);
+
+    if !is_unchanged {
+        fs::write(&encrypted_path, encrypt(plain, &secret)?)?;
+    }
+
+    Ok(encrypted_path)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{decrypt, encrypt, EncryptionError};
+
+    #[test]
+    fn round_trips() {
+        let encrypted = encrypt("3   4\n4   3\n", "secret").unwrap();
+        assert_ne!(encrypted, b"3   4\n4   3\n");
+        assert_eq!(decrypt(&encrypted, "secret").unwrap(), "3   4\n4   3\n");
+    }
+
+    #[test]
+    fn uses_a_new_nonce_for_each_encryption() {
+        assert_ne!(
+            encrypt("1\n", "secret").unwrap(),
+            encrypt("1\n", "secret").unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_wrong_key() {
+        let encrypted = encrypt("1\n", "secret").unwrap();
+        assert!(matches!(
+            decrypt(&encrypted, "other"),
+            Err(EncryptionError::WrongKey)
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(matches!(
+            decrypt(b"short", "secret"),
+            Err(EncryptionError::Malformed)
+        ));
+
+        let mut encrypted = encrypt("1\n", "secret").unwrap();
+        let last = encrypted.len() - 1;
+        encrypted[last] ^= 1;
+        assert!(matches!(
+            decrypt(&encrypted, "secret"),
+            Err(EncryptionError::WrongKey)
+        ));
+    }
+}
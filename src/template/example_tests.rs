@@ -0,0 +1,479 @@
+/// Generates the tests module of a solution from the example files of a day.
+///
+/// Every example file gets one test per part it is used for, named after the file:
+/// `test_part_one` for `01.txt` and `test_part_one_example_2` for `01-2.txt`, which is read
+/// with [`read_file_part`](crate::template::read_file_part).
+/// Expected answers come from a sidecar file next to the example, e.g. `01-2.answers`:
+///
+/// ```text
+/// part_one: 11
+/// part_two: 31
+/// ```
+///
+/// If a sidecar exists, only the parts listed in it are tested. Otherwise `01.txt` is used for both parts and
+/// `01-k.txt` for part `k`, and a part is tested against `None`, unless the solution already has a test of that name,
+/// which is kept as it was written.
+use std::fs;
+
+use crate::template::{paths, Part, PuzzleId};
+
+const TESTS_MODULE: &str = "#[cfg(test)]\nmod tests {";
+
+/// An example file of a day and its expected answers.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    /// `None` for `01.txt`, which is shared by both parts, `Some(part)` for `01-<part>.txt`.
+    pub part: Option<Part>,
+    /// Expected answers of the parts listed in the sidecar, `None` without a sidecar.
+    pub answers: Option<Vec<(Part, String)>>,
+}
+
+impl Example {
+    /// Path of the example file.
+    pub fn path(&self, puzzle: PuzzleId) -> String {
+        paths::example(puzzle, self.part.map(Part::into_inner))
+    }
+
+    /// The parts the example is tested with: the parts listed in its sidecar,
+    /// or without a sidecar both parts for the shared example and its own part otherwise.
+    pub fn parts(&self, puzzle: PuzzleId) -> Vec<Part> {
+        [Part::One, Part::Two]
+            .into_iter()
+            .filter(|part| puzzle.has_part(*part))
+            .filter(|part| match (&self.answers, self.part) {
+                (Some(answers), _) => answers.iter().any(|(p, _)| p == part),
+                (None, Some(own)) => own == *part,
+                (None, None) => true,
+            })
+            .collect()
+    }
+
+    /// The expected answer of a part. `Some(None)` without a sidecar, `None` if the sidecar does not list the part.
+    pub fn answer(&self, part: Part) -> Option<Option<&str>> {
+        match &self.answers {
+            Some(answers) => Some(Some(answers.iter().find(|(p, _)| *p == part)?.1.as_str())),
+            None => Some(None),
+        }
+    }
+}
+
+/// Name of the solution function of a part, e.g. `part_one`.
+pub fn function_name(part: Part) -> &'static str {
+    match part {
+        Part::One => "part_one",
+        Part::Two => "part_two",
+    }
+}
+
+fn has_content(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty())
+}
+
+/// Parses a sidecar file with lines like `part_one: 11`. Blank lines and `#` comments are ignored.
+pub fn parse_answers(s: &str) -> Vec<(Part, String)> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (key, answer) = line.split_once(':')?;
+            let part = [Part::One, Part::Two]
+                .into_iter()
+                .find(|part| function_name(*part) == key.trim())?;
+            Some((part, answer.trim().to_string()))
+        })
+        .collect()
+}
+
+fn load_example(puzzle: PuzzleId, part: Option<Part>) -> Option<Example> {
+    let number = part.map(Part::into_inner);
+    if !has_content(&paths::example(puzzle, number)) {
+        return None;
+    }
+
+    let answers = fs::read_to_string(paths::day_file("examples", puzzle, number, "answers"))
+        .ok()
+        .map(|s| parse_answers(&s));
+
+    Some(Example { part, answers })
+}
+
+/// Returns all example files of a day that have content.
+pub fn find_examples(puzzle: PuzzleId) -> Vec<Example> {
+    [None, Some(Part::One), Some(Part::Two)]
+        .into_iter()
+        .filter(|part| part.is_none_or(|part| puzzle.has_part(part)))
+        .filter_map(|part| load_example(puzzle, part))
+        .collect()
+}
+
+/// The example a part is tested with: its own example if there is one, the shared example otherwise.
+pub fn example_of_part(examples: &[Example], part: Part) -> Option<&Example> {
+    examples
+        .iter()
+        .find(|example| example.part == Some(part))
+        .or_else(|| examples.iter().find(|example| example.part.is_none()))
+}
+
+/// The answer type of a solution, read from the signature of `part_one`, e.g. `u64` for `-> Option<u64>`.
+pub fn answer_type_of(source: &str) -> Option<String> {
+    let signature = &source[source.find("fn part_one(")?..];
+    let signature = &signature[..signature.find('{')?];
+    let inner = signature.split_once("-> Option<")?.1.trim_end();
+    Some(inner.strip_suffix('>')?.trim().to_string())
+}
+
+/// Formats an answer as the expected value of a part, e.g. `Some(11)` or `Some("abc".to_string())`.
+/// Answers of a `String` answer type are always formatted as strings, even if they are numeric.
+fn expected_value(answer: Option<&str>, answer_type: &str) -> String {
+    match answer {
+        None => "None".into(),
+        Some(answer) if answer_type != "String" && answer.parse::<i128>().is_ok() => {
+            format!("Some({answer})")
+        }
+        Some(answer) => format!("Some({answer:?}.to_string())"),
+    }
+}
+
+/// The span of the tests module of a solution, from `#[cfg(test)]` to its closing brace and line break.
+/// Braces in string literals, e.g. in `format!` calls, are not counted.
+fn tests_module_span(source: &str) -> Option<(usize, usize)> {
+    let start = source.find(TESTS_MODULE)?;
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in source[start..].char_indices() {
+        if in_string {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let end = start + i + 1;
+                    let end = if source[end..].starts_with('\n') {
+                        end + 1
+                    } else {
+                        end
+                    };
+                    return Some((start, end));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// The test function `name` in the tests module of a solution, with its `#[test]` attribute.
+fn existing_test<'a>(source: &'a str, name: &str) -> Option<&'a str> {
+    const END: &str = "\n    }\n";
+
+    let (start, end) = tests_module_span(source)?;
+    let tests = &source[start..end];
+    let signature = tests.find(&format!("    fn {name}() {{\n"))?;
+    let start = tests[..signature].rfind("    #[test]\n")?;
+    let end = signature + tests[signature..].find(END)? + END.len();
+    Some(&tests[start..end])
+}
+
+/// Name of the test of a part with an example, e.g. `test_part_one` or `test_part_one_example_2`.
+fn test_name(example: &Example, part: Part) -> String {
+    match example.part {
+        None => format!("test_{}", function_name(part)),
+        Some(number) => format!("test_{}_example_{number}", function_name(part)),
+    }
+}
+
+fn generate_test(
+    examples: &[Example],
+    example: &Example,
+    part: Part,
+    source: &str,
+    answer_type: &str,
+) -> Option<String> {
+    let answer = example.answer(part)?;
+    let function = function_name(part);
+    let name = test_name(example, part);
+
+    // without a known answer, a test that was written by hand is better than one that expects `None`.
+    // hand-written tests of a part's own example are named `test_part_one` unless there is a shared example.
+    if answer.is_none() {
+        let own_example = example.part == Some(part) && examples.iter().all(|e| e.part.is_some());
+        let existing = existing_test(source, &name).or_else(|| {
+            own_example
+                .then(|| existing_test(source, &format!("test_{function}")))
+                .flatten()
+        });
+
+        if let Some(test) = existing {
+            return Some(test.to_string());
+        }
+    }
+
+    let read = match example.part {
+        None => format!(
+            "{function}(&advent_of_code::template::read_file(\"examples\", PUZZLE))"
+        ),
+        Some(part) => format!(
+            "{function}(&advent_of_code::template::read_file_part(\n            \"examples\",\n            PUZZLE,\n            advent_of_code::part!({part}),\n        ))"
+        ),
+    };
+
+    Some(format!(
+        "    #[test]\n    fn {name}() {{\n        let result = {read};\n        assert_eq!(result, {});\n    }}\n",
+        expected_value(answer, answer_type)
+    ))
+}
+
+/// Generates a tests module with one test per example file and part,
+/// keeping hand-written tests of `source` without a known answer.
+pub fn generate(puzzle: PuzzleId, examples: &[Example], source: &str, answer_type: &str) -> String {
+    let tests: Vec<String> = examples
+        .iter()
+        .flat_map(|example| {
+            example
+                .parts(puzzle)
+                .into_iter()
+                .filter_map(|part| generate_test(examples, example, part, source, answer_type))
+        })
+        .collect();
+
+    format!(
+        "{TESTS_MODULE}\n    use super::*;\n\n{}}}\n",
+        tests.join("\n")
+    )
+}
+
+/// Replaces the tests module of a solution, keeping any code around it.
+/// Returns `None` if the solution has no tests module.
+pub fn replace_tests(source: &str, tests: &str) -> Option<String> {
+    let (start, end) = tests_module_span(source)?;
+    Some(format!("{}{tests}{}", &source[..start], &source[end..]))
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{answer_type_of, generate, parse_answers, replace_tests, Example};
+    use crate::{part, puzzle_id};
+
+    #[test]
+    fn parses_answers() {
+        assert_eq!(
+            parse_answers("# day 1\npart_one: 11\n\npart_two:  abc \npart_three: 1\n"),
+            vec![(part!(1), "11".into()), (part!(2), "abc".into())]
+        );
+    }
+
+    #[test]
+    fn generates_one_test_per_example_file() {
+        let examples = [
+            Example {
+                part: None,
+                answers: Some(vec![(part!(1), "11".into())]),
+            },
+            Example {
+                part: Some(part!(2)),
+                answers: Some(vec![(part!(1), "3".into()), (part!(2), "48".into())]),
+            },
+        ];
+
+        let expected = [
+            "#[cfg(test)]",
+            "mod tests {",
+            "    use super::*;",
+            "",
+            "    #[test]",
+            "    fn test_part_one() {",
+            "        let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "        assert_eq!(result, Some(11));",
+            "    }",
+            "",
+            "    #[test]",
+            "    fn test_part_one_example_2() {",
+            "        let result = part_one(&advent_of_code::template::read_file_part(",
+            "            \"examples\",",
+            "            PUZZLE,",
+            "            advent_of_code::part!(2),",
+            "        ));",
+            "        assert_eq!(result, Some(3));",
+            "    }",
+            "",
+            "    #[test]",
+            "    fn test_part_two_example_2() {",
+            "        let result = part_two(&advent_of_code::template::read_file_part(",
+            "            \"examples\",",
+            "            PUZZLE,",
+            "            advent_of_code::part!(2),",
+            "        ));",
+            "        assert_eq!(result, Some(48));",
+            "    }",
+            "}",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(
+            generate(puzzle_id!(2024, 1), &examples, "", "u32"),
+            expected
+        );
+    }
+
+    #[test]
+    fn tests_examples_without_answers_with_their_parts() {
+        let examples = [
+            Example {
+                part: None,
+                answers: None,
+            },
+            Example {
+                part: Some(part!(2)),
+                answers: None,
+            },
+        ];
+
+        let tests = generate(puzzle_id!(2024, 1), &examples, "", "u32");
+        assert!(tests.contains("fn test_part_one() {"));
+        assert!(tests.contains("fn test_part_two() {"));
+        assert!(tests.contains("fn test_part_two_example_2() {"));
+        assert!(!tests.contains("fn test_part_one_example_2() {"));
+        assert_eq!(tests.matches("assert_eq!(result, None);").count(), 3);
+    }
+
+    #[test]
+    fn generates_string_answers_and_skips_missing_parts() {
+        let examples = [Example {
+            part: None,
+            answers: Some(vec![(part!(1), "abc".into()), (part!(2), "1".into())]),
+        }];
+
+        let tests = generate(puzzle_id!(2024, 25), &examples, "", "u32");
+        assert!(tests.contains("assert_eq!(result, Some(\"abc\".to_string()));"));
+        assert!(!tests.contains("part_two"));
+
+        let examples = [Example {
+            part: Some(part!(1)),
+            answers: None,
+        }];
+        assert!(!generate(puzzle_id!(2024, 1), &examples, "", "u32").contains("part_two"));
+    }
+
+    #[test]
+    fn generates_numeric_answers_of_string_answer_types_as_strings() {
+        let examples = [Example {
+            part: None,
+            answers: Some(vec![(part!(1), "11".into())]),
+        }];
+
+        let tests = generate(puzzle_id!(2024, 1), &examples, "", "String");
+        assert!(tests.contains("assert_eq!(result, Some(\"11\".to_string()));"));
+    }
+
+    #[test]
+    fn reads_the_answer_type_of_a_solution() {
+        let source = "pub fn part_one(input: &str) -> Option<u64> {\n    None\n}\n";
+        assert_eq!(answer_type_of(source), Some("u64".into()));
+        assert_eq!(
+            answer_type_of("pub fn part_one(_: &str) -> Option<String> {}"),
+            Some("String".into())
+        );
+        assert_eq!(answer_type_of("pub fn part_one(_: &str) -> u64 {}"), None);
+    }
+
+    #[test]
+    fn keeps_hand_written_tests_without_answers() {
+        let source = [
+            "solution!(3);",
+            "",
+            "#[cfg(test)]",
+            "mod tests {",
+            "    use super::*;",
+            "",
+            "    #[test]",
+            "    fn test_part_one() {",
+            "        let result = part_one(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "        assert_eq!(result, Some(161));",
+            "    }",
+            "",
+            "    #[test]",
+            "    fn test_part_two() {",
+            "        let result = part_two(&advent_of_code::template::read_file(\"examples\", PUZZLE));",
+            "        assert_eq!(result, Some(47));",
+            "    }",
+            "}",
+            "",
+        ]
+        .join("\n");
+
+        let examples = [Example {
+            part: None,
+            answers: None,
+        }];
+        let tests = generate(puzzle_id!(2024, 3), &examples, &source, "u32");
+        assert!(source.ends_with(&tests));
+
+        let examples = [Example {
+            part: None,
+            answers: Some(vec![(part!(1), "161".into()), (part!(2), "48".into())]),
+        }];
+        let tests = generate(puzzle_id!(2024, 3), &examples, &source, "u32");
+        assert!(tests.contains("assert_eq!(result, Some(161));"));
+        assert!(tests.contains("assert_eq!(result, Some(48));"));
+        assert!(!tests.contains("Some(47)"));
+    }
+
+    #[test]
+    fn keeps_hand_written_tests_of_part_examples() {
+        let source = [
+            "#[cfg(test)]",
+            "mod tests {",
+            "    use super::*;",
+            "",
+            "    #[test]",
+            "    fn test_part_one() {",
+            "        let result = part_one(\"{}\");",
+            "        assert_eq!(result, Some(161));",
+            "    }",
+            "}",
+            "",
+        ]
+        .join("\n");
+
+        let examples = [Example {
+            part: Some(part!(1)),
+            answers: None,
+        }];
+        let tests = generate(puzzle_id!(2024, 3), &examples, &source, "u32");
+        assert!(tests.contains("    fn test_part_one() {\n        let result = part_one(\"{}\");"));
+        assert!(!tests.contains("test_part_one_example_1"));
+    }
+
+    #[test]
+    fn replaces_tests_module() {
+        let source = "solution!(1);\n\n#[cfg(test)]\nmod tests {\n    old\n}\n";
+        assert_eq!(
+            replace_tests(source, "#[cfg(test)]\nmod tests {}\n"),
+            Some("solution!(1);\n\n#[cfg(test)]\nmod tests {}\n".into())
+        );
+        assert_eq!(replace_tests("solution!(1);\n", ""), None);
+
+        let source =
+            "#[cfg(test)]\nmod tests {\n    fn a() {\n        \"}\";\n    }\n}\n\nfn after() {}\n";
+        assert_eq!(
+            replace_tests(source, "#[cfg(test)]\nmod tests {}\n"),
+            Some("#[cfg(test)]\nmod tests {}\n\nfn after() {}\n".into())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+/// Test harness that replaces the `aoc` executable with a scripted fake.
+///
+/// A `FakeAoc` writes an `aoc` shell script to a temporary directory and puts it first on `PATH`.
+/// The script records the arguments of every call before it runs the script body given by the test.
+/// `AOC_DATA_DIR` and `AOC_BIN_DIR` point to the same directory, so downloads, ledgers and header updates
+/// never touch the project's `data` and `src/bin` folders.
+///
+/// The environment is global to the test process, so only one `FakeAoc` can exist at a time.
+/// Creating one blocks until the previous one is dropped, which restores the environment.
+/// Other tests do not take this lock, so a `FakeAoc` only changes variables that are read by the tests using it.
+/// `AOC_YEAR` is read all over, e.g. by [`Year::event_length`](crate::template::Year::event_length),
+/// so it is left alone. Tests pass it to the binaries they run instead.
+use std::{
+    env,
+    ffi::OsString,
+    fs,
+    os::unix::fs::PermissionsExt,
+    path::{Path, PathBuf},
+    process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, MutexGuard, PoisonError,
+    },
+};
+
+static LOCK: Mutex<()> = Mutex::new(());
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+const CALL_SEPARATOR: &str = "---";
+const ENV_VARS: [&str; 4] = ["PATH", "AOC_DATA_DIR", "AOC_BIN_DIR", "AOC_INPUT_KEY"];
+
+pub struct FakeAoc {
+    dir: PathBuf,
+    previous_env: Vec<(&'static str, Option<OsString>)>,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl FakeAoc {
+    /// Installs a fake `aoc` that runs `body` as a shell script, e.g. `echo "That's the right answer!"`.
+    pub fn install(body: &str) -> Self {
+        let fake = Self::new();
+
+        let script = format!(
+            "#!/bin/sh\nfor arg in \"$@\"; do printf '%s\\n' \"$arg\" >> \"{log}\"; done\necho '{CALL_SEPARATOR}' >> \"{log}\"\n{body}\n",
+            log = fake.log_path().display()
+        );
+
+        let script_path = fake.script_dir().join("aoc");
+        fs::write(&script_path, script).unwrap();
+        fs::set_permissions(&script_path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        fake
+    }
+
+    /// Removes `aoc` from `PATH`, as if aoc-cli was not installed.
+    pub fn not_installed() -> Self {
+        Self::new()
+    }
+
+    fn new() -> Self {
+        let guard = LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let dir = env::temp_dir().join(format!(
+            "aoc-fake-{}-{}",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        fs::create_dir_all(dir.join("bin")).unwrap();
+        fs::create_dir_all(dir.join("data")).unwrap();
+        fs::create_dir_all(dir.join("solutions")).unwrap();
+
+        let previous_env = ENV_VARS.iter().map(|k| (*k, env::var_os(k))).collect();
+
+        // keep the system paths, so that the fake can call `sh`, `printf` and friends.
+        env::set_var(
+            "PATH",
+            format!("{}:/usr/bin:/bin", dir.join("bin").display()),
+        );
+        env::set_var("AOC_DATA_DIR", dir.join("data"));
+        env::set_var("AOC_BIN_DIR", dir.join("solutions"));
+        env::set_var("AOC_INPUT_KEY", "fake-aoc");
+
+        Self {
+            dir,
+            previous_env,
+            _guard: guard,
+        }
+    }
+
+    /// The directory that `AOC_DATA_DIR` points to.
+    pub fn data_dir(&self) -> PathBuf {
+        self.dir.join("data")
+    }
+
+    /// The directory that `AOC_BIN_DIR` points to.
+    pub fn solutions_dir(&self) -> PathBuf {
+        self.dir.join("solutions")
+    }
+
+    fn script_dir(&self) -> PathBuf {
+        self.dir.join("bin")
+    }
+
+    fn log_path(&self) -> PathBuf {
+        self.dir.join("calls.log")
+    }
+
+    /// Arguments of every call to the fake, in order.
+    pub fn calls(&self) -> Vec<Vec<String>> {
+        let log = fs::read_to_string(self.log_path()).unwrap_or_default();
+        let mut calls = vec![];
+        let mut current = vec![];
+
+        for line in log.lines() {
+            if line == CALL_SEPARATOR {
+                calls.push(std::mem::take(&mut current));
+            } else {
+                current.push(line.to_string());
+            }
+        }
+
+        calls
+    }
+
+    /// Arguments of the last call to the fake.
+    pub fn last_call(&self) -> Vec<String> {
+        self.calls().pop().expect("the fake aoc was not called.")
+    }
+
+    /// Reads a file relative to the data directory.
+    pub fn read_data_file(&self, path: impl AsRef<Path>) -> Option<String> {
+        fs::read_to_string(self.data_dir().join(path)).ok()
+    }
+
+    /// Writes a file relative to the data directory.
+    pub fn write_data_file(&self, path: impl AsRef<Path>, contents: &str) {
+        let path = self.data_dir().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+}
+
+impl Drop for FakeAoc {
+    fn drop(&mut self) {
+        for (key, value) in &self.previous_env {
+            match value {
+                Some(value) => env::set_var(key, value),
+                None => env::remove_var(key),
+            }
+        }
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
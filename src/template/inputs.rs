@@ -0,0 +1,99 @@
+/// Checks for downloaded puzzle inputs.
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputError {
+    Empty,
+    NotLoggedIn,
+    NotUnlocked,
+    Html,
+    MissingTrailingNewline,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Empty => write!(f, "the downloaded input is empty."),
+            InputError::NotLoggedIn => write!(
+                f,
+                "the website asked to log in, the session cookie is missing or expired."
+            ),
+            InputError::NotUnlocked => write!(f, "the puzzle has not been unlocked yet."),
+            InputError::Html => write!(f, "the downloaded input is an html page."),
+            InputError::MissingTrailingNewline => write!(
+                f,
+                "the downloaded input does not end with a newline and might be truncated."
+            ),
+        }
+    }
+}
+
+/// Validates the contents of a downloaded input, flagging error pages of the website and truncated files.
+pub fn validate(input: &str) -> Result<(), InputError> {
+    let head = input
+        .trim_start()
+        .chars()
+        .take(9)
+        .collect::<String>()
+        .to_lowercase();
+
+    if input.trim().is_empty() {
+        Err(InputError::Empty)
+    } else if input.contains("Please log in to get your puzzle input") {
+        Err(InputError::NotLoggedIn)
+    } else if input.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        Err(InputError::NotUnlocked)
+    } else if ["<!doctype", "<html", "<head", "<body"]
+        .iter()
+        .any(|tag| head.starts_with(tag))
+    {
+        Err(InputError::Html)
+    } else if !input.ends_with('\n') {
+        Err(InputError::MissingTrailingNewline)
+    } else {
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{validate, InputError};
+
+    #[test]
+    fn accepts_inputs() {
+        assert_eq!(validate("3   4\n4   3\n"), Ok(()));
+        assert_eq!(validate("<>^v\n"), Ok(()));
+    }
+
+    #[test]
+    fn flags_empty_inputs() {
+        assert_eq!(validate(""), Err(InputError::Empty));
+        assert_eq!(validate("\n \n"), Err(InputError::Empty));
+    }
+
+    #[test]
+    fn flags_error_pages() {
+        assert_eq!(
+            validate("Puzzle inputs differ by user.  Please log in to get your puzzle input.\n"),
+            Err(InputError::NotLoggedIn)
+        );
+        assert_eq!(
+            validate("Please don't repeatedly request this endpoint before it unlocks! The calendar countdown is synchronized with the server time.\n"),
+            Err(InputError::NotUnlocked)
+        );
+        assert_eq!(
+            validate("<!DOCTYPE html>\n<html lang=\"en-us\">\n</html>\n"),
+            Err(InputError::Html)
+        );
+    }
+
+    #[test]
+    fn flags_missing_trailing_newline() {
+        assert_eq!(
+            validate("3   4\n4   3"),
+            Err(InputError::MissingTrailingNewline)
+        );
+    }
+}
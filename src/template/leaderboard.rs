@@ -0,0 +1,293 @@
+/// Data model of the private leaderboard JSON API.
+use std::{collections::HashMap, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, Year};
+
+/// Times a member got the stars of a single day, as seconds since the unix epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayCompletion {
+    pub day: Day,
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub stars: u64,
+    pub last_star_ts: i64,
+    pub completions: Vec<DayCompletion>,
+}
+
+impl Member {
+    /// Name of the member, anonymous users are shown like on the website.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    pub fn completion(&self, day: Day) -> Option<&DayCompletion> {
+        self.completions.iter().find(|c| c.day == day)
+    }
+}
+
+/// A private leaderboard. Can be deserialized from the JSON API of the website.
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub event: i64,
+    pub members: Vec<Member>,
+}
+
+impl Leaderboard {
+    /// Members ordered by local score. Ties are broken by who got their last star first.
+    pub fn standings(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+        });
+        members
+    }
+
+    /// The year of this event, `None` if the leaderboard names a year before the first event.
+    pub fn year(&self) -> Option<Year> {
+        Year::new(u16::try_from(self.event).ok()?)
+    }
+
+    /// Unlock time of a day of this event, as seconds since the unix epoch.
+    pub fn unlock_timestamp(&self, day: Day) -> i64 {
+        day.unlock_timestamp(self.event)
+    }
+
+    /// Seconds it took a member to get the stars of a day, counted from the unlock.
+    pub fn completion_times(&self, completion: &DayCompletion) -> (Option<i64>, Option<i64>) {
+        let unlock = self.unlock_timestamp(completion.day);
+        (
+            completion.part_1.map(|ts| ts - unlock),
+            completion.part_2.map(|ts| ts - unlock),
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Reads a number that the API might encode either as a JSON number or as a string.
+fn get_number(json: &HashMap<String, JsonValue>, key: &str) -> Option<f64> {
+    match json.get(key)? {
+        JsonValue::Number(n) => Some(*n),
+        JsonValue::String(s) => s.parse().ok(),
+        _ => None,
+    }
+}
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let event = get_number(json, "event").ok_or("expected `json.event` to be a year.")? as i64;
+
+        let members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected `json.members` to be an object.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Leaderboard { event, members })
+    }
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let id = get_number(json, "id").ok_or("Expected member.id to be a number.")? as u64;
+        let name = json.get("name").and_then(|v| v.get::<String>()).cloned();
+        let local_score = get_number(json, "local_score")
+            .ok_or("Expected member.local_score to be a number.")? as u64;
+        let stars =
+            get_number(json, "stars").ok_or("Expected member.stars to be a number.")? as u64;
+        let last_star_ts = get_number(json, "last_star_ts").unwrap_or_default() as i64;
+
+        let mut completions = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("Expected member.completion_day_level to be an object.")?
+            .iter()
+            .map(|(day, parts)| {
+                let day = day
+                    .parse::<Day>()
+                    .map_err(|_| format!("Expected `{day}` to be a day."))?;
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected completion to be a JSON object.")?;
+                let get_star_ts = |part: &str| {
+                    parts
+                        .get(part)
+                        .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+                        .and_then(|v| get_number(v, "get_star_ts"))
+                        .map(|ts| ts as i64)
+                };
+
+                Ok(DayCompletion {
+                    day,
+                    part_1: get_star_ts("1"),
+                    part_2: get_star_ts("2"),
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        completions.sort_unstable_by_key(|c| c.day);
+
+        Ok(Member {
+            id,
+            name,
+            local_score,
+            stars,
+            last_star_ts,
+            completions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayCompletion, Leaderboard};
+    use crate::day;
+
+    const FIXTURE: &str = r#"{
+        "event": "2024",
+        "owner_id": 1001,
+        "members": {
+            "1001": {
+                "id": 1001,
+                "name": "Alice",
+                "local_score": 10,
+                "global_score": 0,
+                "stars": 3,
+                "last_star_ts": 1733117400,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1733030100, "star_index": 1 },
+                        "2": { "get_star_ts": 1733030400, "star_index": 2 }
+                    },
+                    "2": {
+                        "1": { "get_star_ts": 1733117400, "star_index": 3 }
+                    }
+                }
+            },
+            "1002": {
+                "id": "1002",
+                "name": null,
+                "local_score": 12,
+                "global_score": 0,
+                "stars": 2,
+                "last_star_ts": 1733031000,
+                "completion_day_level": {
+                    "1": {
+                        "1": { "get_star_ts": 1733030060, "star_index": 4 },
+                        "2": { "get_star_ts": 1733031000, "star_index": 5 }
+                    }
+                }
+            },
+            "1003": {
+                "id": 1003,
+                "name": "Carol",
+                "local_score": 0,
+                "global_score": 0,
+                "stars": 0,
+                "last_star_ts": 0,
+                "completion_day_level": {}
+            }
+        }
+    }"#;
+
+    fn get_fixture() -> Leaderboard {
+        Leaderboard::try_from(FIXTURE.to_string()).unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = get_fixture();
+        assert_eq!(leaderboard.event, 2024);
+        assert_eq!(leaderboard.year(), Some(crate::year!(2024)));
+        assert_eq!(leaderboard.members.len(), 3);
+    }
+
+    #[test]
+    fn parses_members() {
+        let leaderboard = get_fixture();
+        let alice = leaderboard.members.iter().find(|m| m.id == 1001).unwrap();
+
+        assert_eq!(alice.display_name(), "Alice");
+        assert_eq!(alice.stars, 3);
+        assert_eq!(
+            alice.completions,
+            vec![
+                DayCompletion {
+                    day: day!(1),
+                    part_1: Some(1_733_030_100),
+                    part_2: Some(1_733_030_400),
+                },
+                DayCompletion {
+                    day: day!(2),
+                    part_1: Some(1_733_117_400),
+                    part_2: None,
+                },
+            ]
+        );
+
+        let anonymous = leaderboard.members.iter().find(|m| m.id == 1002).unwrap();
+        assert_eq!(anonymous.display_name(), "(anonymous user #1002)");
+    }
+
+    #[test]
+    fn orders_standings_by_score() {
+        let leaderboard = get_fixture();
+        let ids: Vec<u64> = leaderboard.standings().iter().map(|m| m.id).collect();
+        assert_eq!(ids, vec![1002, 1001, 1003]);
+    }
+
+    #[test]
+    fn computes_completion_times_from_unlock() {
+        let leaderboard = get_fixture();
+        // 2024-12-01T05:00:00Z
+        assert_eq!(leaderboard.unlock_timestamp(day!(1)), 1_733_029_200);
+
+        let alice = leaderboard.members.iter().find(|m| m.id == 1001).unwrap();
+        assert_eq!(
+            leaderboard.completion_times(alice.completion(day!(1)).unwrap()),
+            (Some(900), Some(1200))
+        );
+        assert_eq!(
+            leaderboard.completion_times(alice.completion(day!(2)).unwrap()),
+            (Some(1800), None)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_leaderboards() {
+        Leaderboard::try_from(r#"{ "event": "2024", "members": [] }"#.to_string()).unwrap();
+    }
+}
@@ -0,0 +1,346 @@
+/// Renders puzzle descriptions for the terminal.
+/// Supports the subset of markdown found in puzzle descriptions: headings, emphasis, inline code, links, lists and code blocks.
+use std::{
+    env,
+    process::{Command, Stdio},
+};
+
+use regex::Regex;
+
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const DEFAULT_WIDTH: usize = 80;
+const MIN_WIDTH: usize = 20;
+const CODE_INDENT: &str = "    ";
+
+/// Width of the terminal in columns, read from `COLUMNS` or `stty`.
+pub fn terminal_width() -> usize {
+    let from_env = env::var("COLUMNS").ok().and_then(|x| x.parse().ok());
+
+    let from_stty = || {
+        let output = Command::new("stty")
+            .arg("size")
+            .stdin(Stdio::inherit())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+        String::from_utf8_lossy(&output.stdout)
+            .split_whitespace()
+            .nth(1)?
+            .parse()
+            .ok()
+    };
+
+    from_env
+        .or_else(from_stty)
+        .unwrap_or(DEFAULT_WIDTH)
+        .max(MIN_WIDTH)
+}
+
+/// A word of a paragraph, including its formatting.
+#[derive(Default)]
+struct Word {
+    text: String,
+    len: usize,
+}
+
+/// Formatting of inline markdown: emphasis is rendered bold, code italic.
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+struct Style {
+    emphasis: bool,
+    code: bool,
+}
+
+impl Style {
+    /// Whether switching from `from` to this style turns formatting off, which takes a reset.
+    fn needs_reset(self, from: Style) -> bool {
+        (from.emphasis && !self.emphasis) || (from.code && !self.code)
+    }
+
+    /// ANSI codes that switch the terminal from `from` to this style.
+    fn codes_from(self, from: Style) -> String {
+        let mut codes = String::new();
+        let from = if self.needs_reset(from) {
+            codes.push_str(ANSI_RESET);
+            Style::default()
+        } else {
+            from
+        };
+
+        if self.emphasis && !from.emphasis {
+            codes.push_str(ANSI_BOLD);
+        }
+        if self.code && !from.code {
+            codes.push_str(ANSI_ITALIC);
+        }
+        codes
+    }
+}
+
+/// Splits inline markdown into words, replacing markup with ANSI codes.
+/// Codes are only emitted where the style changes, so a style that spans several words
+/// carries over the spaces and line breaks between them.
+fn split_words(text: &str) -> Vec<Word> {
+    let links = Regex::new(r"\[([^\]]*)\]\([^)]*\)").unwrap();
+    let text = links.replace_all(text, "$1");
+
+    let mut words = vec![];
+    let mut current = Word::default();
+    // the style of the markup read so far, and the style the emitted text is in.
+    let mut style = Style::default();
+    let mut emitted = Style::default();
+
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let literal = match c {
+            '\\' => chars.next(),
+            '`' => {
+                style.code = !style.code;
+                None
+            }
+            '*' if !style.code => {
+                while chars.peek() == Some(&'*') {
+                    chars.next();
+                }
+                style.emphasis = !style.emphasis;
+                None
+            }
+            c if c.is_whitespace() => {
+                if current.len > 0 {
+                    // a style that ends with a word is reset at its end rather than at the next word.
+                    if style.needs_reset(emitted) {
+                        current.text.push_str(ANSI_RESET);
+                        emitted = Style::default();
+                    }
+                    words.push(std::mem::take(&mut current));
+                }
+                None
+            }
+            c => Some(c),
+        };
+
+        if let Some(c) = literal {
+            current.text.push_str(&style.codes_from(emitted));
+            emitted = style;
+            current.text.push(c);
+            current.len += 1;
+        }
+    }
+
+    if current.len > 0 {
+        words.push(current);
+    }
+
+    if emitted != Style::default() {
+        if let Some(last) = words.last_mut() {
+            last.text.push_str(ANSI_RESET);
+        }
+    }
+
+    words
+}
+
+/// Wraps words into lines of at most `width` visible characters.
+fn wrap(words: &[Word], width: usize, prefix: &str, indent: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = prefix.to_string();
+    let mut len = prefix.chars().count();
+    let mut is_empty = true;
+
+    for word in words {
+        if !is_empty && len + 1 + word.len > width {
+            lines.push(line);
+            line = indent.to_string();
+            len = indent.chars().count();
+            is_empty = true;
+        }
+
+        if !is_empty {
+            line.push(' ');
+            len += 1;
+        }
+
+        line.push_str(&word.text);
+        len += word.len;
+        is_empty = false;
+    }
+
+    if !is_empty {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// A block of text that is collected line by line before it is rendered.
+#[derive(Default)]
+struct Paragraph {
+    text: String,
+    is_list_item: bool,
+}
+
+impl Paragraph {
+    fn push(&mut self, line: &str) {
+        if !self.text.is_empty() {
+            self.text.push(' ');
+        }
+        self.text.push_str(line.trim());
+    }
+
+    fn render(&self, width: usize, is_heading: bool, out: &mut Vec<String>) {
+        if self.text.is_empty() {
+            return;
+        }
+
+        let words = split_words(&self.text);
+
+        if is_heading {
+            out.extend(
+                wrap(&words, width, "", "")
+                    .into_iter()
+                    .map(|line| format!("{ANSI_BOLD}{line}{ANSI_RESET}")),
+            );
+        } else if self.is_list_item {
+            out.extend(wrap(&words, width, "  - ", "    "));
+        } else {
+            out.extend(wrap(&words, width, "", ""));
+        }
+    }
+}
+
+fn push_blank(out: &mut Vec<String>) {
+    if out.last().is_some_and(|l| !l.is_empty()) {
+        out.push(String::new());
+    }
+}
+
+fn is_setext_underline(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+/// Renders markdown for the terminal, word-wrapped to `width` columns.
+pub fn render(markdown: &str, width: usize) -> String {
+    let mut out: Vec<String> = vec![];
+    let mut paragraph = Paragraph::default();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            paragraph.render(width, false, &mut out);
+            paragraph = Paragraph::default();
+
+            if !in_code_block {
+                push_blank(&mut out);
+            }
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            out.push(format!("{CODE_INDENT}{line}"));
+        } else if trimmed.is_empty() {
+            paragraph.render(width, false, &mut out);
+            paragraph = Paragraph::default();
+            push_blank(&mut out);
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            paragraph.render(width, false, &mut out);
+            paragraph = Paragraph::default();
+            paragraph.push(heading.trim_start_matches('#'));
+            push_blank(&mut out);
+            paragraph.render(width, true, &mut out);
+            paragraph = Paragraph::default();
+        } else if is_setext_underline(trimmed) && !paragraph.text.is_empty() {
+            paragraph.render(width, true, &mut out);
+            paragraph = Paragraph::default();
+        } else if let Some(item) = trimmed
+            .strip_prefix("* ")
+            .or_else(|| trimmed.strip_prefix("- "))
+        {
+            paragraph.render(width, false, &mut out);
+            paragraph = Paragraph {
+                is_list_item: true,
+                ..Paragraph::default()
+            };
+            paragraph.push(item);
+        } else {
+            paragraph.push(trimmed);
+        }
+    }
+
+    paragraph.render(width, false, &mut out);
+
+    while out.last().is_some_and(String::is_empty) {
+        out.pop();
+    }
+
+    out.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::render;
+    use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+    #[test]
+    fn renders_headings() {
+        let rendered = render(
+            "\\--- Day 1: Historian Hysteria ---\n----------\n\n## --- Part Two ---\n",
+            80,
+        );
+        assert_eq!(
+            rendered,
+            format!("{ANSI_BOLD}--- Day 1: Historian Hysteria ---{ANSI_RESET}\n\n{ANSI_BOLD}--- Part Two ---{ANSI_RESET}")
+        );
+    }
+
+    #[test]
+    fn renders_emphasis_and_code() {
+        let rendered = render(
+            "The *total distance* is *`11`*, see [here](https://x.y).",
+            80,
+        );
+        assert_eq!(
+            rendered,
+            format!("The {ANSI_BOLD}total distance{ANSI_RESET} is {ANSI_BOLD}{ANSI_ITALIC}11{ANSI_RESET}, see here.")
+        );
+    }
+
+    #[test]
+    fn emits_codes_only_where_the_style_changes() {
+        let rendered = render("*a `b` c* d", 80);
+        assert_eq!(
+            rendered,
+            format!("{ANSI_BOLD}a {ANSI_ITALIC}b{ANSI_RESET} {ANSI_BOLD}c{ANSI_RESET} d")
+        );
+
+        // a style that is still active at the end of a paragraph is reset.
+        assert_eq!(render("`x y", 80), format!("{ANSI_ITALIC}x y{ANSI_RESET}"));
+    }
+
+    #[test]
+    fn wraps_paragraphs_and_lists() {
+        let rendered = render(
+            "one two three four\nfive six\n\n* seven eight nine ten\n* eleven\n",
+            12,
+        );
+        assert_eq!(
+            rendered,
+            "one two\nthree four\nfive six\n\n  - seven\n    eight\n    nine ten\n  - eleven"
+        );
+    }
+
+    #[test]
+    fn keeps_code_blocks() {
+        let rendered = render(
+            "For example:\n\n```\n3   4\n4   3 with a very long line\n```\n\nDone.",
+            12,
+        );
+        assert_eq!(
+            rendered,
+            "For example:\n\n    3   4\n    4   3 with a very long line\n\nDone."
+        );
+    }
+}
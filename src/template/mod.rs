@@ -1,57 +1,123 @@
-use std::{env, fs};
+use std::fs;
 
 pub mod aoc_cli;
+#[cfg(feature = "client")]
+pub mod aoc_client;
+pub mod bench;
 pub mod commands;
 pub mod runner;
 
 pub use day::*;
+pub use part::*;
+pub use puzzle_id::*;
+pub use verdict::*;
+pub use year::*;
 
+mod backup;
+mod bench_target;
+#[cfg(feature = "today")]
+mod clock;
 mod day;
+mod day_check;
+mod durations;
+#[cfg(feature = "encrypted-inputs")]
+mod encryption;
+mod example_tests;
+#[cfg(all(feature = "test_lib", unix))]
+pub mod fake_aoc;
+mod inputs;
+mod leaderboard;
+mod markdown;
+mod module_template;
+mod part;
+mod paths;
+mod puzzle;
+mod puzzle_id;
 mod readme_benchmarks;
 mod run_multi;
+mod solve_times;
+mod submissions;
+mod timestamp;
 mod timings;
+mod verdict;
+mod watch;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
+/// A [`Day`] is read from the configured year.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file(folder: &str, puzzle: impl Into<PuzzleId>) -> String {
+    read_data_file(&paths::day_file(folder, puzzle.into(), None, "txt"))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
+pub fn read_file_part(folder: &str, puzzle: impl Into<PuzzleId>, part: Part) -> String {
+    read_data_file(&paths::day_file(
+        folder,
+        puzzle.into(),
+        Some(part.into_inner()),
+        "txt",
+    ))
+}
+
+#[cfg(not(feature = "encrypted-inputs"))]
+fn read_data_file(path: &str) -> String {
+    let f = fs::read_to_string(path);
     f.expect("could not open input file")
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Falls back to the encrypted copy of a file if the plain file is missing, e.g. in CI.
+#[cfg(feature = "encrypted-inputs")]
+fn read_data_file(path: &str) -> String {
+    match fs::read_to_string(path) {
+        Ok(f) => f,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => encryption::read_encrypted(path)
+            .unwrap_or_else(|e| panic!("could not read encrypted input file: {e}")),
+        Err(e) => panic!("could not open input file: {e}"),
+    }
+}
+
+/// Creates the constants `DAY` and `PUZZLE` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// The year of the puzzle is passed last, e.g. `solution!(1, year = 2024)`.
+/// Without a year, only `DAY` is created and the puzzle of the configured year is solved.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@day $day, [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@day $day, [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@day $day, [part_two, 2]);
+    };
+    ($day:expr, year = $year:expr) => {
+        $crate::solution!(@year $day, $year, [part_one, 1] [part_two, 2]);
     };
+    ($day:expr, 1, year = $year:expr) => {
+        $crate::solution!(@year $day, $year, [part_one, 1]);
+    };
+    ($day:expr, 2, year = $year:expr) => {
+        $crate::solution!(@year $day, $year, [part_two, 2]);
+    };
+
+    (@year $day:expr, $year:expr, $( [$func:expr, $part:expr] )*) => {
+        /// The puzzle this binary solves.
+        const PUZZLE: $crate::template::PuzzleId = $crate::puzzle_id!($year, $day);
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@impl $day, PUZZLE, $( [$func, $part] )*);
+    };
+    (@day $day:expr, $( [$func:expr, $part:expr] )*) => {
+        $crate::solution!(@impl $day, $crate::template::PuzzleId::from(DAY), $( [$func, $part] )*);
+    };
+    (@impl $day:expr, $puzzle:expr, $( [$func:expr, $part:expr] )*) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -61,8 +127,9 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let puzzle = $puzzle;
+            let input = $crate::template::read_file("inputs", puzzle);
+            $( run_part($func, &input, puzzle, $crate::part!($part)); )*
         }
     };
 }
@@ -0,0 +1,292 @@
+/// Templates that `scaffold` creates solution modules from.
+///
+/// Named templates live in `./templates/<name>.txt`, `AOC_TEMPLATE` sets the one used by default.
+/// Without a name, the built-in template is used.
+/// For the last day of an event, `./templates/<name>_single_part.txt` is preferred if it exists.
+///
+/// Solutions start with a doc header that names the puzzle and links to it.
+use std::{env, fmt::Display, fs, io, path::Path};
+
+use crate::template::{paths, Part, PuzzleId};
+
+const BUILT_IN: &str = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
+
+/// Built-in template for days without a second part.
+const BUILT_IN_SINGLE_PART: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/src/template_single_part.txt"
+));
+
+const DEFAULT_ANSWER_TYPE: &str = "u32";
+
+const PUZZLE_URL: &str = "https://adventofcode.com";
+
+/// First line of the doc header, followed by the title if it is known.
+const HEADER_PREFIX: &str = "//! # Day ";
+
+#[derive(Debug)]
+pub enum Error {
+    NotFound(String),
+    IO(io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::NotFound(path) => write!(f, "no template found at \"{path}\""),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::IO(e)
+    }
+}
+
+/// Values that are substituted into a template.
+pub struct Placeholders<'a> {
+    pub puzzle: PuzzleId,
+    pub title: &'a str,
+    pub answer_type: &'a str,
+}
+
+/// Name of the template to use, the given one or the one set in `AOC_TEMPLATE`.
+fn template_name(name: Option<&str>) -> Option<String> {
+    name.map(str::to_string).or_else(|| {
+        env::var("AOC_TEMPLATE")
+            .ok()
+            .filter(|x| !x.trim().is_empty())
+    })
+}
+
+/// Reads the template for a puzzle.
+pub fn load(name: Option<&str>, puzzle: PuzzleId) -> Result<String, Error> {
+    let single_part = !puzzle.has_part(Part::Two);
+
+    let Some(name) = template_name(name) else {
+        return Ok(if single_part {
+            BUILT_IN_SINGLE_PART
+        } else {
+            BUILT_IN
+        }
+        .to_string());
+    };
+
+    let single_part_path = paths::template(&format!("{name}_single_part"));
+    let path = if single_part && Path::new(&single_part_path).exists() {
+        single_part_path
+    } else {
+        paths::template(&name)
+    };
+
+    if !Path::new(&path).exists() {
+        return Err(Error::NotFound(path));
+    }
+
+    Ok(fs::read_to_string(path)?)
+}
+
+/// The answer type to use, the given one or the one set in `AOC_ANSWER_TYPE`. Defaults to `u32`.
+pub fn answer_type(answer_type: Option<String>) -> String {
+    answer_type
+        .or_else(|| env::var("AOC_ANSWER_TYPE").ok())
+        .map(|x| x.trim().to_string())
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| DEFAULT_ANSWER_TYPE.into())
+}
+
+/// Substitutes the placeholders of a template:
+/// `%DAY_NUMBER%` (e.g. `6`), `%DAY%` (e.g. `06`), `%YEAR%`, `%TITLE%` and `%ANSWER_TYPE%`.
+pub fn render(template: &str, placeholders: &Placeholders) -> String {
+    let puzzle = placeholders.puzzle;
+    template
+        .replace("%DAY_NUMBER%", &puzzle.day.into_inner().to_string())
+        .replace("%DAY%", &puzzle.day.to_string())
+        .replace("%YEAR%", &puzzle.year.to_string())
+        .replace("%TITLE%", placeholders.title)
+        .replace("%ANSWER_TYPE%", placeholders.answer_type)
+}
+
+/// The doc header of a solution, e.g.:
+///
+/// ```text
+/// //! # Day 6: Guard Gallivant
+/// //!
+/// //! <https://adventofcode.com/2024/day/6>
+/// ```
+pub fn header(puzzle: PuzzleId, title: Option<&str>) -> String {
+    let day = puzzle.day.into_inner();
+    let heading = match title {
+        Some(title) => format!("{HEADER_PREFIX}{day}: {title}"),
+        None => format!("{HEADER_PREFIX}{day}"),
+    };
+    format!(
+        "{heading}\n//!\n//! <{PUZZLE_URL}/{}/day/{day}>\n",
+        puzzle.year
+    )
+}
+
+/// Length of the header that [`header`] generated at the top of a solution, `0` if there is none.
+/// Only the title line, the blank separator and the link line count, so that doc comments after them are kept.
+fn header_len(source: &str) -> usize {
+    let mut lines = source.split_inclusive('\n');
+    let matchers: [&dyn Fn(&str) -> bool; 3] = [
+        &|line| line.starts_with(HEADER_PREFIX),
+        &|line| line == "//!",
+        &|line| line.starts_with(&format!("//! <{PUZZLE_URL}/")) && line.ends_with('>'),
+    ];
+
+    let mut len = 0;
+    for matches in matchers {
+        match lines.next() {
+            Some(line) if matches(line.trim_end_matches('\n')) => len += line.len(),
+            _ => break,
+        }
+    }
+    len
+}
+
+/// Inserts a doc header at the top of a solution, or replaces the header that is already there.
+/// Other doc comments at the top of a solution are kept.
+pub fn with_header(source: &str, header: &str) -> String {
+    let body = source[header_len(source)..].trim_start_matches('\n');
+    format!("{header}\n{body}")
+}
+
+/// Returns the title from the doc header of a solution, e.g. "Guard Gallivant".
+pub fn title_from_header(source: &str) -> Option<&str> {
+    let heading = source.lines().next()?.strip_prefix(HEADER_PREFIX)?;
+    let (_, title) = heading.split_once(':')?;
+    Some(title.trim()).filter(|x| !x.is_empty())
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{header, load, render, title_from_header, with_header, Error, Placeholders};
+    use crate::puzzle_id;
+    use crate::template::puzzle::title;
+
+    #[test]
+    fn renders_placeholders() {
+        let placeholders = Placeholders {
+            puzzle: puzzle_id!(2024, 6),
+            title: "Guard Gallivant",
+            answer_type: "u64",
+        };
+        assert_eq!(
+            render(
+                "// %TITLE% (%YEAR%-%DAY%)\nsolution!(%DAY_NUMBER%, year = %YEAR%);\nOption<%ANSWER_TYPE%>",
+                &placeholders
+            ),
+            "// Guard Gallivant (2024-06)\nsolution!(6, year = 2024);\nOption<u64>"
+        );
+    }
+
+    #[test]
+    fn formats_headers() {
+        assert_eq!(
+            header(puzzle_id!(2024, 6), Some("Guard Gallivant")),
+            "//! # Day 6: Guard Gallivant\n//!\n//! <https://adventofcode.com/2024/day/6>\n"
+        );
+        assert_eq!(
+            header(puzzle_id!(2023, 25), None),
+            "//! # Day 25\n//!\n//! <https://adventofcode.com/2023/day/25>\n"
+        );
+    }
+
+    #[test]
+    fn inserts_and_refreshes_headers() {
+        let source = "advent_of_code::solution!(6, year = 2024);\n";
+        let untitled = with_header(source, &header(puzzle_id!(2024, 6), None));
+        assert_eq!(
+            untitled,
+            "//! # Day 6\n//!\n//! <https://adventofcode.com/2024/day/6>\n\nadvent_of_code::solution!(6, year = 2024);\n"
+        );
+        assert_eq!(title_from_header(&untitled), None);
+
+        let titled = with_header(
+            &untitled,
+            &header(puzzle_id!(2024, 6), Some("Guard Gallivant")),
+        );
+        assert_eq!(
+            titled,
+            "//! # Day 6: Guard Gallivant\n//!\n//! <https://adventofcode.com/2024/day/6>\n\nadvent_of_code::solution!(6, year = 2024);\n"
+        );
+        assert_eq!(title_from_header(&titled), Some("Guard Gallivant"));
+    }
+
+    #[test]
+    fn keeps_other_doc_comments() {
+        let source = "//! My notes.\nadvent_of_code::solution!(6, year = 2024);\n";
+        let result = with_header(source, &header(puzzle_id!(2024, 6), None));
+        assert!(result.ends_with("\n\n//! My notes.\nadvent_of_code::solution!(6, year = 2024);\n"));
+
+        let source = "//! # Day 6\n//!\n//! <https://adventofcode.com/2024/day/6>\n//! My notes.\n";
+        let result = with_header(
+            source,
+            &header(puzzle_id!(2024, 6), Some("Guard Gallivant")),
+        );
+        assert!(result.ends_with("/day/6>\n\n//! My notes.\n"));
+    }
+
+    #[test]
+    fn keeps_doc_comments_after_the_header() {
+        let notes =
+            "//! My notes.\n//!\n//! More notes.\n\nadvent_of_code::solution!(6, year = 2024);\n";
+        let source = format!("{}{notes}", header(puzzle_id!(2024, 6), None));
+
+        let result = with_header(
+            &source,
+            &header(puzzle_id!(2024, 6), Some("Guard Gallivant")),
+        );
+        assert_eq!(
+            result,
+            format!(
+                "{}\n{notes}",
+                header(puzzle_id!(2024, 6), Some("Guard Gallivant"))
+            )
+        );
+    }
+
+    #[test]
+    fn refreshes_headers_from_client_descriptions() {
+        let description = "## --- Day 6: Guard Gallivant ---\n\nPart one.\n";
+        let source = with_header(
+            "advent_of_code::solution!(6, year = 2024);\n",
+            &header(puzzle_id!(2024, 6), None),
+        );
+
+        let refreshed = with_header(&source, &header(puzzle_id!(2024, 6), title(description)));
+        assert!(refreshed.starts_with("//! # Day 6: Guard Gallivant\n//!\n"));
+        assert_eq!(title_from_header(&refreshed), Some("Guard Gallivant"));
+        assert!(refreshed.ends_with("/day/6>\n\nadvent_of_code::solution!(6, year = 2024);\n"));
+    }
+
+    #[test]
+    fn loads_built_in_templates() {
+        let template = load(None, puzzle_id!(2024, 6)).unwrap();
+        assert!(template.contains("part_two"));
+
+        let template = load(None, puzzle_id!(2024, 25)).unwrap();
+        assert!(template.contains("part_one"));
+        assert!(!template.contains("part_two"));
+    }
+
+    #[test]
+    fn loads_named_templates() {
+        let template = load(Some("grid"), puzzle_id!(2024, 6)).unwrap();
+        assert!(template.contains("%ANSWER_TYPE%"));
+    }
+
+    #[test]
+    fn errors_for_missing_templates() {
+        match load(Some("does-not-exist"), puzzle_id!(2024, 6)) {
+            Err(Error::NotFound(path)) => assert_eq!(path, "./templates/does-not-exist.txt"),
+            _ => panic!("expected the template to be missing"),
+        }
+    }
+}
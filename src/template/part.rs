@@ -0,0 +1,117 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A part of a puzzle. Every day has two parts, the second one unlocks once the first one is solved.
+///
+/// # Display
+/// This value displays as its number.
+///
+/// ```
+/// # use advent_of_code::template::Part;
+/// let part = Part::new(2).unwrap();
+/// assert_eq!(part.to_string(), "2")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Creates a [`Part`] from the provided value if it's 1 or 2,
+    /// returns [`None`] otherwise.
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Self::One),
+            2 => Some(Self::Two),
+            _ => None,
+        }
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(part: u8) -> Self {
+        if part == 1 {
+            Self::One
+        } else {
+            Self::Two
+        }
+    }
+
+    /// Converts the [`Part`] into its number.
+    pub fn into_inner(self) -> u8 {
+        match self {
+            Self::One => 1,
+            Self::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Part {
+    type Err = PartFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let part = s.parse().map_err(|_| PartFromStrError)?;
+        Self::new(part).ok_or(PartFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Part`].
+#[derive(Debug)]
+pub struct PartFromStrError;
+
+impl Error for PartFromStrError {}
+
+impl Display for PartFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a part number of 1 or 2")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Part`] value in a const context.
+#[macro_export]
+macro_rules! part {
+    ($part:expr) => {{
+        const _ASSERT: () = assert!(
+            $part == 1 || $part == 2,
+            concat!("invalid part number `", $part, "`, expecting 1 or 2"),
+        );
+        $crate::template::Part::__new_unchecked($part)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Part;
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!(Part::from_str("1").ok(), Some(Part::One));
+        assert_eq!(Part::from_str("2").ok(), Some(Part::Two));
+        assert!(Part::from_str("0").is_err());
+        assert!(Part::from_str("7").is_err());
+        assert!(Part::from_str("two").is_err());
+    }
+
+    #[test]
+    fn round_trips_numbers() {
+        assert_eq!(Part::new(1).map(Part::into_inner), Some(1));
+        assert_eq!(Part::new(2).map(Part::into_inner), Some(2));
+        assert_eq!(crate::part!(2), Part::Two);
+    }
+}
@@ -0,0 +1,259 @@
+/// Resolves the locations of inputs, examples, puzzles and other data files.
+///
+/// All files live below a root directory, `data` by default, which can be changed with `AOC_DATA_DIR`.
+/// Files of a day follow the pattern in `AOC_DATA_PATTERN`, `{year}/{folder}/{day}.{ext}` by default.
+/// The pattern supports the placeholders `{folder}`, `{day}`, `{year}` and `{ext}`.
+/// Files of a single part append the part to the day, e.g. `2024/examples/01-2.txt`.
+///
+/// Solutions live in `./src/bin`, `AOC_BIN_DIR` moves them elsewhere, e.g. to sandbox tests.
+use std::{
+    env, fs, io,
+    path::{Component, Path, PathBuf},
+};
+
+use crate::template::{PuzzleId, Year};
+
+const DEFAULT_DATA_DIR: &str = "data";
+const DEFAULT_BIN_DIR: &str = "./src/bin";
+const DEFAULT_PATTERN: &str = "{year}/{folder}/{day}.{ext}";
+
+/// Root directory of all data files.
+#[must_use]
+pub fn data_dir() -> String {
+    env::var("AOC_DATA_DIR")
+        .ok()
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| DEFAULT_DATA_DIR.into())
+}
+
+fn pattern() -> String {
+    env::var("AOC_DATA_PATTERN")
+        .ok()
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| DEFAULT_PATTERN.into())
+}
+
+fn join(root: &str, path: &str) -> String {
+    Path::new(root).join(path).to_string_lossy().into_owned()
+}
+
+fn expand(pattern: &str, folder: &str, day: &str, ext: &str, year: Year) -> String {
+    pattern
+        .replace("{folder}", folder)
+        .replace("{day}", day)
+        .replace("{ext}", ext)
+        .replace("{year}", &year.to_string())
+}
+
+/// Path of a file of a day, e.g. `data/2024/inputs/01.txt`.
+#[must_use]
+pub fn day_file(folder: &str, puzzle: PuzzleId, part: Option<u8>, ext: &str) -> String {
+    let day = match part {
+        Some(part) => format!("{}-{part}", puzzle.day),
+        None => puzzle.day.to_string(),
+    };
+
+    join(
+        &data_dir(),
+        &expand(&pattern(), folder, &day, ext, puzzle.year),
+    )
+}
+
+#[must_use]
+pub fn input(puzzle: PuzzleId) -> String {
+    day_file("inputs", puzzle, None, "txt")
+}
+
+/// Path of an example. An example of a single part appends the part, e.g. `01-2.txt`.
+#[must_use]
+pub fn example(puzzle: PuzzleId, part: Option<u8>) -> String {
+    day_file("examples", puzzle, part, "txt")
+}
+
+#[must_use]
+pub fn puzzle(puzzle: PuzzleId) -> String {
+    day_file("puzzles", puzzle, None, "md")
+}
+
+/// Paths of all data files a day can have, whether they exist or not:
+/// its input, examples with their answers and the puzzle description.
+#[must_use]
+pub fn day_data_files(puzzle: PuzzleId) -> Vec<String> {
+    let mut files = vec![input(puzzle)];
+
+    for number in [None, Some(1), Some(2)] {
+        files.push(example(puzzle, number));
+        files.push(day_file("examples", puzzle, number, "answers"));
+    }
+
+    files.push(self::puzzle(puzzle));
+    files
+}
+
+/// Path of a file that is shared by all years, e.g. `data/timings.json`.
+#[must_use]
+pub fn shared_file(name: &str) -> String {
+    join(&data_dir(), name)
+}
+
+/// Path of a file that is shared by all days of a year, e.g. `data/2024/submissions.json`.
+/// These files are always stored in a folder per year, independent of `AOC_DATA_PATTERN`.
+#[must_use]
+pub fn year_file(year: Year, name: &str) -> String {
+    join(&data_dir(), &format!("{year}/{name}"))
+}
+
+/// Creates the directory a file is written to.
+/// The data directory is configurable, so its folders might not exist yet.
+pub fn create_parent_dir(path: &str) -> io::Result<()> {
+    match Path::new(path).parent() {
+        Some(parent) => fs::create_dir_all(parent),
+        None => Ok(()),
+    }
+}
+
+/// Directory of the solution binaries, `./src/bin` unless `AOC_BIN_DIR` is set.
+#[must_use]
+pub fn bin_dir() -> String {
+    env::var("AOC_BIN_DIR")
+        .ok()
+        .filter(|x| !x.is_empty())
+        .unwrap_or_else(|| DEFAULT_BIN_DIR.into())
+}
+
+/// Path of the solution binary of a puzzle, e.g. `./src/bin/2024-01.rs`.
+/// Solutions are not part of the data directory.
+#[must_use]
+pub fn bin(puzzle: PuzzleId) -> String {
+    join(&bin_dir(), &format!("{puzzle}.rs"))
+}
+
+/// Resolves a path against `base` and removes `.` and `..` components without touching the file system.
+fn normalize(base: &Path, path: &str) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in base.join(path).components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+
+    normalized
+}
+
+/// Expresses `path` relative to the directory `dir`, e.g. `../src/bin/2024-01.rs` for `./src/bin/2024-01.rs`
+/// relative to `benches`. Relative inputs are resolved against `cwd`. Paths below `dir` start with `./`.
+fn relative_in(cwd: &Path, path: &str, dir: &str) -> String {
+    let path = normalize(cwd, path);
+    let dir = normalize(cwd, dir);
+
+    let path: Vec<Component> = path.components().collect();
+    let dir: Vec<Component> = dir.components().collect();
+    let common = path.iter().zip(&dir).take_while(|(a, b)| a == b).count();
+
+    let mut parts: Vec<String> = vec!["..".to_string(); dir.len() - common];
+    if parts.is_empty() {
+        parts.push(".".into());
+    }
+    parts.extend(
+        path[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+
+    parts.join("/")
+}
+
+/// Expresses `path` relative to the directory `dir`, both relative to the working directory or absolute.
+/// Used for links in the README and `#[path]` attributes in benches, e.g. `../src/bin/2024-01.rs`.
+#[must_use]
+pub fn relative_to(path: &str, dir: &str) -> String {
+    let cwd = env::current_dir().unwrap_or_default();
+    relative_in(&cwd, path, dir)
+}
+
+/// Path of the bench of a puzzle, e.g. `./benches/2024-01.rs`.
+#[must_use]
+pub fn bench(puzzle: PuzzleId) -> String {
+    format!("./benches/{puzzle}.rs")
+}
+
+/// Path of a named scaffold template, e.g. `./templates/grid.txt`.
+#[must_use]
+pub fn template(name: &str) -> String {
+    format!("./templates/{name}.txt")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::path::Path;
+
+    use super::{expand, join, relative_in, DEFAULT_PATTERN};
+    use crate::year;
+
+    #[test]
+    fn expands_default_pattern() {
+        assert_eq!(
+            join(
+                "data",
+                &expand(DEFAULT_PATTERN, "inputs", "01", "txt", year!(2024))
+            ),
+            "data/2024/inputs/01.txt"
+        );
+        assert_eq!(
+            join(
+                "data",
+                &expand(DEFAULT_PATTERN, "examples", "01-2", "txt", year!(2023))
+            ),
+            "data/2023/examples/01-2.txt"
+        );
+    }
+
+    #[test]
+    fn expands_custom_pattern() {
+        assert_eq!(
+            join(
+                "/shared/aoc",
+                &expand(
+                    "{folder}/{year}-day{day}.{ext}",
+                    "puzzles",
+                    "05",
+                    "md",
+                    year!(2024)
+                )
+            ),
+            "/shared/aoc/puzzles/2024-day05.md"
+        );
+        assert_eq!(
+            expand("{folder}/{day}.{ext}", "inputs", "01", "txt", year!(2024)),
+            "inputs/01.txt"
+        );
+    }
+
+    #[test]
+    fn expresses_paths_relative_to_a_directory() {
+        let cwd = Path::new("/home/aoc");
+        assert_eq!(
+            relative_in(cwd, "./src/bin/2024-01.rs", "."),
+            "./src/bin/2024-01.rs"
+        );
+        assert_eq!(
+            relative_in(cwd, "./src/bin/2024-01.rs", "benches"),
+            "../src/bin/2024-01.rs"
+        );
+        assert_eq!(
+            relative_in(cwd, "/home/aoc/solutions/2024-01.rs", "."),
+            "./solutions/2024-01.rs"
+        );
+        assert_eq!(
+            relative_in(cwd, "/tmp/aoc/2024-01.rs", "./benches"),
+            "../../../tmp/aoc/2024-01.rs"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+/// Helpers to work with puzzle descriptions downloaded to the puzzles folder.
+use std::fs;
+
+use crate::template::{paths, Part, PuzzleId};
+
+/// Reads the markdown puzzle description of a day, if it was downloaded.
+pub fn read_puzzle(puzzle: PuzzleId) -> Option<String> {
+    fs::read_to_string(paths::puzzle(puzzle)).ok()
+}
+
+/// Returns the title of a puzzle from its "--- Day 6: Guard Gallivant ---" heading.
+/// aoc-cli escapes the heading as `\--- Day 6: …`, the built-in client writes it as `## --- Day 6: …`.
+pub fn title(markdown: &str) -> Option<&str> {
+    let heading = markdown
+        .lines()
+        .next()?
+        .trim_start_matches(|c: char| c == '#' || c == '\\' || c.is_whitespace())
+        .trim();
+    let title = heading.strip_prefix("--- Day ")?.strip_suffix("---")?;
+    let (_, title) = title.split_once(':')?;
+    Some(title.trim()).filter(|x| !x.is_empty())
+}
+
+/// Returns the contents of all fenced code blocks in a markdown document.
+/// Each block ends with a newline, like a downloaded input.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut current: Option<String> = None;
+
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            match current.take() {
+                Some(block) => blocks.push(block),
+                None => current = Some(String::new()),
+            }
+        } else if let Some(block) = current.as_mut() {
+            block.push_str(line);
+            block.push('\n');
+        }
+    }
+
+    blocks
+}
+
+/// Returns the description of a single part.
+/// Part two starts at the "--- Part Two ---" heading and is only present once part one is solved.
+pub fn select_part(markdown: &str, part: Part) -> Option<&str> {
+    let part_two_start = markdown
+        .find("--- Part Two ---")
+        .map(|i| markdown[..i].rfind('\n').map_or(0, |j| j + 1));
+
+    match (part, part_two_start) {
+        (Part::One, Some(i)) => Some(&markdown[..i]),
+        (Part::One, None) => Some(markdown),
+        (Part::Two, Some(i)) => Some(&markdown[i..]),
+        (Part::Two, None) => None,
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{code_blocks, select_part, title};
+    use crate::part;
+
+    // excerpt of a puzzle description as written by aoc-cli.
+    const PUZZLE: &str = r"\--- Day 1: Historian Hysteria ---
+----------
+
+For example:
+
+```
+3   4
+4   3
+2   5
+```
+
+Pair up the smallest number in the left list with the smallest number in the right list.
+
+```
+1
+```
+";
+
+    #[test]
+    fn extracts_code_blocks() {
+        assert_eq!(code_blocks(PUZZLE), vec!["3   4\n4   3\n2   5\n", "1\n"]);
+    }
+
+    #[test]
+    fn extracts_titles() {
+        assert_eq!(title(PUZZLE), Some("Historian Hysteria"));
+        assert_eq!(
+            title("--- Day 6: Guard Gallivant ---\n"),
+            Some("Guard Gallivant")
+        );
+        assert_eq!(
+            title("## --- Day 6: Guard Gallivant ---\n\nThe guard.\n"),
+            Some("Guard Gallivant")
+        );
+        assert_eq!(title("\\--- Day 1 ---\n"), None);
+        assert_eq!(title("Some text.\n"), None);
+    }
+
+    #[test]
+    fn selects_parts() {
+        let markdown =
+            "\\--- Day 1 ---\n----------\n\nOne.\n\n\\--- Part Two ---\n----------\n\nTwo.\n";
+        assert_eq!(
+            select_part(markdown, part!(1)),
+            Some("\\--- Day 1 ---\n----------\n\nOne.\n\n")
+        );
+        assert_eq!(
+            select_part(markdown, part!(2)),
+            Some("\\--- Part Two ---\n----------\n\nTwo.\n")
+        );
+        assert_eq!(select_part("One.\n", part!(1)), Some("One.\n"));
+        assert_eq!(select_part("One.\n", part!(2)), None);
+    }
+
+    #[test]
+    fn ignores_unterminated_blocks() {
+        assert_eq!(code_blocks("```\n1 2\n"), Vec::<String>::new());
+        assert_eq!(code_blocks("no blocks here"), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,147 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::template::{Day, Part, Year};
+
+/// Identifies the puzzle of a day in a specific year.
+///
+/// # Display
+/// This value displays as the year and day joined by a dash, which is also the name of its solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2024).unwrap(), Day::new(8).unwrap());
+/// assert_eq!(puzzle.to_string(), "2024-08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    pub year: Year,
+    pub day: Day,
+}
+
+impl PuzzleId {
+    pub const fn new(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    /// Creates a [`PuzzleId`] if the day is part of the event of the year.
+    pub fn try_new(year: Year, day: Day) -> Result<Self, DayBeyondEventError> {
+        let puzzle = Self::new(year, day);
+        if puzzle.is_in_event() {
+            Ok(puzzle)
+        } else {
+            Err(DayBeyondEventError(puzzle))
+        }
+    }
+
+    /// Whether the day is part of the event of the year.
+    pub fn is_in_event(self) -> bool {
+        self.day <= self.year.event_length()
+    }
+
+    /// Whether the puzzle has the given part.
+    /// The last day of an event only has part one, its second star is awarded for collecting all others.
+    pub fn has_part(self, part: Part) -> bool {
+        part == Part::One || self.day != self.year.last_day()
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the puzzle of the current day if an event is running, `None` otherwise.
+    /// `AOC_NOW` overrides the current time with a RFC 3339 timestamp or a date, e.g. `2023-12-05`.
+    pub fn today() -> Option<Self> {
+        crate::template::clock::today_at(crate::template::clock::now())
+    }
+
+    /// Returns the next puzzle to unlock and the time left until it unlocks.
+    /// The puzzle of the current day is returned with no time left once it is live.
+    pub fn next_unlock() -> Option<(Self, chrono::Duration)> {
+        let now = crate::template::clock::now();
+        let (puzzle, unlock) = crate::template::clock::next_unlock_after(now)?;
+        Some((puzzle, unlock - now))
+    }
+}
+
+/// Resolves a day to the puzzle of the [configured](Year::configured) year.
+impl From<Day> for PuzzleId {
+    fn from(day: Day) -> Self {
+        Self::new(Year::configured(), day)
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An error which is returned for a day after the end of an event.
+#[derive(Debug)]
+pub struct DayBeyondEventError(PuzzleId);
+
+impl Error for DayBeyondEventError {}
+
+impl Display for DayBeyondEventError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "day {} is beyond the end of the {} event, which has {} days",
+            self.0.day.into_inner(),
+            self.0.year,
+            self.0.year.event_length()
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`PuzzleId`] value in a const context.
+#[macro_export]
+macro_rules! puzzle_id {
+    ($year:expr, $day:expr) => {
+        $crate::template::PuzzleId::new($crate::year!($year), $crate::day!($day))
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::PuzzleId;
+    use crate::{day, part, puzzle_id, year};
+
+    #[test]
+    fn orders_by_year_first() {
+        assert!(puzzle_id!(2023, 25) < puzzle_id!(2024, 1));
+        assert!(puzzle_id!(2024, 1) < puzzle_id!(2024, 2));
+    }
+
+    #[test]
+    fn displays_as_bin_name() {
+        assert_eq!(puzzle_id!(2015, 7).to_string(), "2015-07");
+    }
+
+    #[test]
+    fn last_day_has_a_single_part() {
+        assert!(puzzle_id!(2024, 1).has_part(part!(2)));
+        assert!(puzzle_id!(2024, 12).has_part(part!(2)));
+        assert!(puzzle_id!(2024, 25).has_part(part!(1)));
+        assert!(!puzzle_id!(2024, 25).has_part(part!(2)));
+        assert!(!puzzle_id!(2025, 12).has_part(part!(2)));
+    }
+
+    #[test]
+    fn rejects_days_beyond_the_event() {
+        assert!(PuzzleId::try_new(year!(2024), day!(25)).is_ok());
+        assert!(PuzzleId::try_new(year!(2025), day!(12)).is_ok());
+        assert_eq!(
+            PuzzleId::try_new(year!(2025), day!(13))
+                .unwrap_err()
+                .to_string(),
+            "day 13 is beyond the end of the 2025 event, which has 12 days"
+        );
+    }
+}
@@ -1,9 +1,11 @@
 /// Module that updates the readme me with timing information.
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
-use std::{fs, io};
+use std::{collections::HashMap, env, fs, io};
 
-use crate::template::timings::Timings;
-use crate::template::Day;
+use crate::template::durations::format_elapsed;
+use crate::template::solve_times::{self, DayStats};
+use crate::template::timings::{Timing, Timings};
+use crate::template::{module_template, paths, Part, PuzzleId};
 
 static MARKER: &str = "<!--- benchmarking table --->";
 
@@ -25,11 +27,6 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     let matches: Vec<_> = readme.match_indices(MARKER).collect();
 
@@ -52,76 +49,189 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
-    let header = format!("{prefix} Benchmarks");
+/// Formats the solve times of a day as a table cell, e.g. `` `00:10:00` / `00:30:00` ``.
+fn format_solve_time(stats: Option<&DayStats>) -> String {
+    let format_part =
+        |part: Option<i64>| part.map_or_else(|| "-".into(), |x| format!("`{}`", format_elapsed(x)));
+
+    match stats {
+        Some(stats) => format!(
+            "{} / {}",
+            format_part(stats.part_1),
+            format_part(stats.part_2)
+        ),
+        None => "-".into(),
+    }
+}
+
+/// Formats the timing of the second part as a table cell.
+/// Days without a second part show a star once the first part is timed, "n/a" otherwise.
+fn format_part_2(timing: &Timing) -> String {
+    match &timing.part_2 {
+        Some(part_2) => format!("`{part_2}`"),
+        None if timing.puzzle.has_part(Part::Two) => "`-`".into(),
+        None if timing.part_1.is_some() => "⭐".into(),
+        None => "n/a".into(),
+    }
+}
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+fn construct_year_table(
+    lines: &mut Vec<String>,
+    timings: Timings,
+    bin_dir: &str,
+    titles: &HashMap<PuzzleId, String>,
+    solve_times: Option<&[DayStats]>,
+) {
+    let (solve_time_header, solve_time_align) = match solve_times {
+        Some(_) => (" Solved in |", " :---: |"),
+        None => ("", ""),
+    };
+
+    lines.push(format!("| Day | Part 1 | Part 2 |{solve_time_header}"));
+    lines.push(format!("| :---: | :---: | :---:  |{solve_time_align}"));
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = format!("{bin_dir}/{}.rs", timing.puzzle);
+        let solve_time = solve_times.map_or_else(String::new, |solve_times| {
+            let stats = solve_times.iter().find(|s| s.puzzle == timing.puzzle);
+            format!(" {} |", format_solve_time(stats))
+        });
+
+        let name = match titles.get(&timing.puzzle) {
+            Some(title) => format!("Day {}: {title}", timing.puzzle.day.into_inner()),
+            None => format!("Day {}", timing.puzzle.day.into_inner()),
+        };
+
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
+            "| [{}]({}) | `{}` | {} |{}",
+            name,
             path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            timing.part_1.as_deref().unwrap_or("-"),
+            format_part_2(&timing),
+            solve_time
         ));
     }
+}
+
+/// Builds one table per year, each with its own total.
+/// With more than one year, the total of all years is appended.
+/// Days link to their solutions in `bin_dir`, relative to the README.
+fn construct_table(
+    prefix: &str,
+    timings: &Timings,
+    bin_dir: &str,
+    titles: &HashMap<PuzzleId, String>,
+    solve_times: Option<&[DayStats]>,
+) -> String {
+    let years = timings.years();
+
+    let mut lines: Vec<String> = vec![MARKER.into(), format!("{prefix} Benchmarks")];
+    let mut total_millis = 0_f64;
+
+    for year in &years {
+        let year_timings = timings.for_year(*year);
+        let year_millis = year_timings.total_millis();
+        total_millis += year_millis;
+
+        lines.push(String::new());
+        lines.push(format!("{prefix}# {year}"));
+        lines.push(String::new());
+        construct_year_table(&mut lines, year_timings, bin_dir, titles, solve_times);
+        lines.push(String::new());
+        lines.push(format!("**Total: {year_millis:.2}ms**"));
+    }
+
+    if years.len() > 1 {
+        lines.push(String::new());
+        lines.push(format!("**Total of all years: {total_millis:.2}ms**"));
+    }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
     lines.push(MARKER.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: &Timings,
+    bin_dir: &str,
+    titles: &HashMap<PuzzleId, String>,
+    solve_times: Option<&[DayStats]>,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, bin_dir, titles, solve_times);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+pub fn update(timings: &Timings) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    // solve times are opt-in, as not everyone wants to publish them.
+    let solve_times = env::var("AOC_README_SOLVE_TIMES")
+        .is_ok_and(|x| x == "true")
+        .then(|| {
+            timings
+                .years()
+                .into_iter()
+                .flat_map(solve_times::read_stats)
+                .collect::<Vec<_>>()
+        });
+    // titles are taken from the doc headers of the solutions.
+    let titles = timings
+        .data
+        .iter()
+        .filter_map(|timing| {
+            let source = fs::read_to_string(paths::bin(timing.puzzle)).ok()?;
+            let title = module_template::title_from_header(&source)?;
+            Some((timing.puzzle, title.to_string()))
+        })
+        .collect();
+    // links are relative to the README, wherever `AOC_BIN_DIR` points.
+    let bin_dir = paths::relative_to(&paths::bin_dir(), ".");
+    update_content(
+        &mut readme,
+        timings,
+        &bin_dir,
+        &titles,
+        solve_times.as_deref(),
+    )?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::collections::HashMap;
+
     use super::{update_content, MARKER};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::{
+        puzzle_id, template::solve_times::DayStats, template::timings::Timing,
+        template::timings::Timings,
+    };
+
+    const BIN_DIR: &str = "./src/bin";
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle_id!(2024, 1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
-                    total_nanos: 3e+10,
+                    total_nanos: 3e+7,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle_id!(2024, 2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
-                    total_nanos: 7e+10,
+                    total_nanos: 7e+7,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle_id!(2024, 4),
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
-                    total_nanos: 9e+10,
+                    total_nanos: 9e+7,
                 },
             ],
         }
@@ -131,28 +241,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &HashMap::new(), None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &HashMap::new(), None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &HashMap::new(), None).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &HashMap::new(), None).unwrap();
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &HashMap::new(), None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,18 +270,20 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &HashMap::new(), None).unwrap();
         let expected = [
             "foo",
             "bar",
             "<!--- benchmarking table --->",
             "## Benchmarks",
             "",
+            "### 2024",
+            "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +292,112 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn format_benchmarks_with_solve_times() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let solve_times = [DayStats {
+            puzzle: puzzle_id!(2024, 1),
+            part_1: Some(600),
+            part_2: None,
+        }];
+        update_content(
+            &mut s,
+            &get_mock_timings(),
+            BIN_DIR,
+            &HashMap::new(),
+            Some(&solve_times),
+        )
+        .unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(lines[5], "| Day | Part 1 | Part 2 | Solved in |");
+        assert_eq!(lines[6], "| :---: | :---: | :---:  | :---: |");
+        assert_eq!(
+            lines[7],
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` | `00:10:00` / - |"
+        );
+        assert_eq!(
+            lines[8],
+            "| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` | - |"
+        );
+    }
+
+    #[test]
+    fn format_benchmarks_with_titles() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let titles = HashMap::from([(puzzle_id!(2024, 2), "Red-Nosed Reports".to_string())]);
+        update_content(&mut s, &get_mock_timings(), BIN_DIR, &titles, None).unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(
+            lines[7],
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` |"
+        );
+        assert_eq!(
+            lines[8],
+            "| [Day 2: Red-Nosed Reports](./src/bin/2024-02.rs) | `30ms` | `40ms` |"
+        );
+    }
+
+    #[test]
+    fn format_single_part_days() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let timings = Timings {
+            data: vec![Timing {
+                puzzle: puzzle_id!(2024, 25),
+                part_1: None,
+                part_2: None,
+                total_nanos: 0_f64,
+            }],
+        };
+        update_content(&mut s, &timings, BIN_DIR, &HashMap::new(), None).unwrap();
+
+        let lines: Vec<&str> = s.lines().collect();
+        assert_eq!(lines[7], "| [Day 25](./src/bin/2024-25.rs) | `-` | n/a |");
+    }
+
+    #[test]
+    fn format_benchmarks_of_several_years() {
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings.data.insert(
+            0,
+            Timing {
+                puzzle: puzzle_id!(2023, 25),
+                part_1: Some("1ms".into()),
+                part_2: None,
+                total_nanos: 1e+6,
+            },
+        );
+        update_content(&mut s, &timings, BIN_DIR, &HashMap::new(), None).unwrap();
+
+        let expected = [
+            "<!--- benchmarking table --->",
+            "## Benchmarks",
+            "",
+            "### 2023",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 25](./src/bin/2023-25.rs) | `1ms` | ⭐ |",
+            "",
+            "**Total: 1.00ms**",
+            "",
+            "### 2024",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---:  |",
+            "| [Day 1](./src/bin/2024-01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2024-02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2024-04.rs) | `40ms` | `50ms` |",
+            "",
+            "**Total: 190.00ms**",
+            "",
+            "**Total of all years: 191.00ms**",
+            "<!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
 }
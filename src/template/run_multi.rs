@@ -1,38 +1,43 @@
 use std::{collections::HashSet, io};
 
-use crate::template::{Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{PuzzleId, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
-};
+use super::timings::{Timing, Timings};
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+pub fn run_multi(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    is_release: bool,
+    is_timed: bool,
+) -> Option<Timings> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles_to_run.len());
 
     let mut need_space = false;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
-            if need_space {
-                println!();
-            }
-            need_space = true;
-
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
-                println!("Not solved.");
-            } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
-            }
-        });
+    // NOTE: run puzzles sorted by year and day.
+    let mut puzzles: Vec<PuzzleId> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
+
+    for puzzle in puzzles {
+        if need_space {
+            println!();
+        }
+        need_space = true;
+
+        println!(
+            "{ANSI_BOLD}Day {} ({}){ANSI_RESET}",
+            puzzle.day, puzzle.year
+        );
+        println!("-------------");
+
+        let output = child_commands::run_solution(puzzle, is_timed, is_release).unwrap();
+
+        if output.is_empty() {
+            println!("Not solved.");
+        } else {
+            let val = child_commands::parse_exec_time(&output, puzzle);
+            timings.push(val);
+        }
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -59,16 +64,11 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
-    use super::{get_path_for_bin, Error};
-    use crate::template::Day;
+    use super::Error;
+    use crate::template::{paths, PuzzleId};
     use std::{
         io::{BufRead, BufReader},
         path::Path,
@@ -76,15 +76,19 @@ pub mod child_commands {
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
+    /// Run the solution bin for a given puzzle
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        is_timed: bool,
+        is_release: bool,
+    ) -> Result<Vec<String>, Error> {
         // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
+        if !Path::new(&paths::bin(puzzle)).exists() {
             return Ok(vec![]);
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        let bin_name = puzzle.to_string();
+        let mut args = vec!["run", "--quiet", "--bin", &bin_name];
 
         if is_release {
             args.push("--release");
@@ -128,9 +132,9 @@ pub mod child_commands {
         Ok(output)
     }
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
+    pub fn parse_exec_time(output: &[String], puzzle: PuzzleId) -> super::Timing {
         let mut timings = super::Timing {
-            day,
+            puzzle,
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
@@ -207,7 +211,7 @@ pub mod child_commands {
     mod tests {
         use super::parse_exec_time;
 
-        use crate::day;
+        use crate::puzzle_id;
 
         #[test]
         fn parses_execution_times() {
@@ -217,7 +221,7 @@ pub mod child_commands {
                     "Part 2: 10 (74.13ms @ 99999 samples)".into(),
                     "".into(),
                 ],
-                day!(1),
+                puzzle_id!(2024, 1),
             );
             assert_approx_eq!(res.total_nanos, 74130074.13_f64);
             assert_eq!(res.part_1.unwrap(), "74.13ns");
@@ -232,7 +236,7 @@ pub mod child_commands {
                     "Part 2: 10s (100ms @ 1 samples)".into(),
                     "".into(),
                 ],
-                day!(1),
+                puzzle_id!(2024, 1),
             );
             assert_approx_eq!(res.total_nanos, 2100000000_f64);
             assert_eq!(res.part_1.unwrap(), "2s");
@@ -247,7 +251,7 @@ pub mod child_commands {
                     "Part 2: ✖        ".into(),
                     "".into(),
                 ],
-                day!(1),
+                puzzle_id!(2024, 1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
             assert_eq!(res.part_1.is_none(), true);
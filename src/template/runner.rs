@@ -2,14 +2,39 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
-use std::process::Output;
+use std::thread;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
 
+#[cfg(not(feature = "client"))]
+use crate::template::aoc_cli;
+#[cfg(feature = "client")]
+use crate::template::aoc_client;
+use crate::template::durations::format_elapsed;
+use crate::template::solve_times::SolveTimes;
+use crate::template::submissions::Submissions;
 use crate::template::ANSI_BOLD;
-use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
+use crate::template::{Part, PuzzleId, Verdict, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// Longest cooldown that `--wait` sits out before resubmitting, unless `AOC_MAX_SUBMIT_WAIT` is set.
+const DEFAULT_MAX_SUBMIT_WAIT: Duration = Duration::from_secs(10 * 60);
+
+/// Longest cooldown that `--wait` sits out, the seconds set in `AOC_MAX_SUBMIT_WAIT` or ten minutes.
+fn max_submit_wait() -> Duration {
+    env::var("AOC_MAX_SUBMIT_WAIT")
+        .ok()
+        .and_then(|x| x.trim().parse().ok())
+        .map_or(DEFAULT_MAX_SUBMIT_WAIT, Duration::from_secs)
+}
+
+/// Run a solution part and submit the result if requested.
+/// Returns the verdict if the result was submitted.
+pub fn run_part<I: Copy, T: Display>(
+    func: impl Fn(I) -> Option<T>,
+    input: I,
+    puzzle: PuzzleId,
+    part: Part,
+) -> Option<Verdict> {
     let part_str = format!("Part {part}");
 
     let (result, duration, samples) =
@@ -17,9 +42,7 @@ pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, da
 
     print_result(&result, &part_str, &format_duration(&duration, samples));
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
-    }
+    submit_result(result?, puzzle, part)
 }
 
 /// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
@@ -127,12 +150,12 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///  2. aoc-cli is installed, or the built-in client is enabled.
+///  3. the answer is not ruled out by previous submissions.
+///
+/// Every submission is recorded in the submission ledger.
+/// With `--wait`, a rate-limited submission is retried once the cooldown expires.
+fn submit_result<T: Display>(result: T, puzzle: PuzzleId, part: Part) -> Option<Verdict> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -146,20 +169,142 @@ fn submit_result<T: Display>(
 
     let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
+    let Some(Ok(part_submit)) = args.get(part_index).map(|x| x.parse::<Part>()) else {
         eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
         process::exit(1);
     };
 
+    if !puzzle.has_part(part_submit) {
+        eprintln!("Day {} has no part {part_submit}.", puzzle.day);
+        process::exit(1);
+    }
+
     if part_submit != part {
         return None;
     }
 
+    let answer = result.to_string();
+    let mut submissions = match Submissions::read_from_file(puzzle.year) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!("Refusing to submit {answer}, {e}. Fix or remove the file first.");
+            return None;
+        }
+    };
+
+    if let Err(rejection) = submissions.check(puzzle.day, part, &answer) {
+        eprintln!("Refusing to submit {answer}: {rejection}");
+        return None;
+    }
+
+    let should_wait = args.contains(&"--wait".into());
+
+    loop {
+        let verdict = match submit(puzzle, part, &answer)? {
+            // the website responds with the same message when a part is already solved.
+            Verdict::WrongLevel if submissions.is_solved(puzzle.day, part) => {
+                Verdict::AlreadySolved
+            }
+            verdict => verdict,
+        };
+
+        println!("{ANSI_BOLD}{verdict}{ANSI_RESET}");
+
+        if verdict.is_recorded() {
+            submissions.record(puzzle.day, part, &answer, verdict);
+
+            if submissions.store_file(puzzle.year).is_err() {
+                eprintln!("Failed to record submission.");
+            }
+        }
+
+        if verdict == Verdict::Correct {
+            print_solve_time(puzzle, part, &submissions);
+        }
+
+        match verdict {
+            Verdict::RateLimited(wait) if should_wait => {
+                let max_wait = max_submit_wait();
+                if wait.is_zero() || wait > max_wait {
+                    eprintln!(
+                        "Not waiting, the cooldown is unknown or exceeds {}s. Set `AOC_MAX_SUBMIT_WAIT` to wait longer.",
+                        max_wait.as_secs()
+                    );
+                    return Some(verdict);
+                }
+                wait_for_cooldown(wait);
+            }
+            _ => return Some(verdict),
+        }
+    }
+}
+
+/// Show how long it took to solve a part, counted from the scaffold or unlock of the day.
+fn print_solve_time(puzzle: PuzzleId, part: Part, submissions: &Submissions) {
+    let stats = SolveTimes::read_or_warn(puzzle.year).day_stats(puzzle, submissions);
+    let elapsed = match part {
+        Part::One => stats.part_1,
+        Part::Two => stats.part_2,
+    };
+
+    if let Some(elapsed) = elapsed {
+        println!(
+            "{ANSI_ITALIC}Solved part {part} in {}.{ANSI_RESET}",
+            format_elapsed(elapsed)
+        );
+    }
+}
+
+/// Show a countdown until the cooldown has expired.
+fn wait_for_cooldown(wait: Duration) {
+    let mut stdout = stdout();
+    // wait a bit longer than requested to not hit the limit again due to clock differences.
+    let deadline = Instant::now() + wait + Duration::from_secs(1);
+
+    while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+        print!(
+            "\r{ANSI_ITALIC}Resubmitting in {}s...{ANSI_RESET} ",
+            remaining.as_secs()
+        );
+        let _ = stdout.flush();
+        thread::sleep(remaining.min(Duration::from_secs(1)));
+    }
+
+    println!("\r{ANSI_ITALIC}Resubmitting...{ANSI_RESET}          ");
+}
+
+/// Submit an answer and return the verdict.
+#[cfg(not(feature = "client"))]
+fn submit(puzzle: PuzzleId, part: Part, answer: &str) -> Option<Verdict> {
     if aoc_cli::check().is_err() {
         eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
         process::exit(1);
     }
 
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    match aoc_cli::submit(puzzle, part, answer) {
+        Ok(verdict) => Some(verdict),
+        Err(aoc_cli::AocCommandError::UnknownResponse(output)) => {
+            println!("{}", String::from_utf8_lossy(&output.stdout).trim());
+            eprintln!("failed to parse the response of aoc-cli.");
+            None
+        }
+        Err(e) => {
+            eprintln!("failed to call aoc-cli: {e}");
+            None
+        }
+    }
+}
+
+/// Submit an answer and return the verdict.
+#[cfg(feature = "client")]
+fn submit(puzzle: PuzzleId, part: Part, answer: &str) -> Option<Verdict> {
+    println!("Submitting result...");
+    match aoc_client::submit(puzzle, part, answer) {
+        Ok(verdict) => Some(verdict),
+        Err(e) => {
+            eprintln!("failed to submit result: {e}");
+            None
+        }
+    }
 }
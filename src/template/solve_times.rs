@@ -0,0 +1,277 @@
+/// Tracks how long it took to solve each day.
+/// A day starts when it is scaffolded, but never before its puzzle unlocks.
+/// A part is solved with its first correct submission in the submission ledger.
+use std::{
+    collections::HashMap,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::submissions::Submissions;
+use crate::template::{all_days, paths, Day, Part, PuzzleId, Year};
+
+const SOLVE_TIMES_FILE_NAME: &str = "solve_times.json";
+
+/// The time a day was scaffolded.
+#[derive(Clone, Debug)]
+pub struct Start {
+    pub day: Day,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+}
+
+/// Start times of all scaffolded days of a year.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct SolveTimes {
+    pub data: Vec<Start>,
+}
+
+/// Seconds it took to solve the parts of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DayStats {
+    pub puzzle: PuzzleId,
+    pub part_1: Option<i64>,
+    pub part_2: Option<i64>,
+}
+
+impl SolveTimes {
+    /// Dehydrate start times to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let path = paths::year_file(year, SOLVE_TIMES_FILE_NAME);
+        paths::create_parent_dir(&path)?;
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate start times from the JSON file of a year. If not present, returns empty start times.
+    /// Start times that can not be read are an error, storing over them would lose the recorded starts.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = paths::year_file(year, SOLVE_TIMES_FILE_NAME);
+
+        match fs::read_to_string(&path) {
+            Ok(json) => SolveTimes::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+        .map_err(|e| format!("could not read \"{path}\": {e}"))
+    }
+
+    /// Like [`SolveTimes::read_from_file`], but reports start times that can not be read and returns empty ones.
+    /// Only for reading, the returned start times must not be stored.
+    pub fn read_or_warn(year: Year) -> Self {
+        Self::read_from_file(year).unwrap_or_else(|e| {
+            eprintln!("Ignoring recorded start times, {e}");
+            Self::default()
+        })
+    }
+
+    /// Records the start of a day, unless it was started before.
+    /// Returns whether the start was recorded.
+    pub fn record_start(&mut self, day: Day, timestamp: u64) -> bool {
+        if self.start(day).is_some() {
+            return false;
+        }
+        self.data.push(Start { day, timestamp });
+        self.data.sort_unstable_by_key(|s| s.day);
+        true
+    }
+
+    pub fn start(&self, day: Day) -> Option<u64> {
+        self.data.iter().find(|s| s.day == day).map(|s| s.timestamp)
+    }
+
+    /// Solve times of a puzzle, given the start times and submissions of its year.
+    /// Days without a recorded start are counted from their unlock.
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn day_stats(&self, puzzle: PuzzleId, submissions: &Submissions) -> DayStats {
+        let unlock = puzzle
+            .day
+            .unlock_timestamp(i64::from(puzzle.year.into_inner()));
+
+        let start = self
+            .start(puzzle.day)
+            .map_or(unlock, |scaffolded| (scaffolded as i64).max(unlock));
+
+        let elapsed = |part: Part| {
+            submissions
+                .solved_at(puzzle.day, part)
+                .map(|x| (x as i64 - start).max(0))
+        };
+
+        DayStats {
+            puzzle,
+            part_1: elapsed(Part::One),
+            part_2: elapsed(Part::Two),
+        }
+    }
+}
+
+/// Solve times of all days of a year with at least one solved part.
+pub fn read_stats(year: Year) -> Vec<DayStats> {
+    let solve_times = SolveTimes::read_or_warn(year);
+    let submissions = Submissions::read_or_warn(year);
+
+    all_days(year)
+        .map(|day| solve_times.day_stats(PuzzleId::new(year, day), &submissions))
+        .filter(|stats| stats.part_1.is_some() || stats.part_2.is_some())
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<SolveTimes> for JsonValue {
+    fn from(value: SolveTimes) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for SolveTimes {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(SolveTimes {
+            data: json_data
+                .iter()
+                .map(Start::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Start> for JsonValue {
+    fn from(value: &Start) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Start {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected start to be a JSON object.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected start.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|v| v as u64)
+            .ok_or("Expected start.timestamp to be a number.")?;
+
+        Ok(Start { day, timestamp })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{DayStats, SolveTimes};
+    use crate::template::{submissions::Submissions, Verdict};
+    use crate::{day, part, puzzle_id};
+
+    // 2024-12-06T05:00:00Z
+    const UNLOCK: u64 = 1_733_461_200;
+
+    fn get_mock_submissions() -> Submissions {
+        let mut submissions = Submissions::default();
+        submissions.record(day!(6), part!(1), "1", Verdict::Correct);
+        submissions.data[0].timestamp = UNLOCK + 600;
+        submissions.record(day!(6), part!(2), "3", Verdict::TooLow);
+        submissions.data[1].timestamp = UNLOCK + 1200;
+        submissions.record(day!(6), part!(2), "5", Verdict::Correct);
+        submissions.data[2].timestamp = UNLOCK + 1800;
+        submissions
+    }
+
+    #[test]
+    fn counts_from_unlock_if_scaffolded_before() {
+        let mut solve_times = SolveTimes::default();
+        solve_times.record_start(day!(6), UNLOCK - 3600);
+
+        assert_eq!(
+            solve_times.day_stats(puzzle_id!(2024, 6), &get_mock_submissions()),
+            DayStats {
+                puzzle: puzzle_id!(2024, 6),
+                part_1: Some(600),
+                part_2: Some(1800),
+            }
+        );
+    }
+
+    #[test]
+    fn counts_from_scaffold_if_scaffolded_after_unlock() {
+        let mut solve_times = SolveTimes::default();
+        solve_times.record_start(day!(6), UNLOCK + 300);
+        // the first start is kept.
+        assert!(!solve_times.record_start(day!(6), UNLOCK + 900));
+
+        assert_eq!(
+            solve_times.day_stats(puzzle_id!(2024, 6), &get_mock_submissions()),
+            DayStats {
+                puzzle: puzzle_id!(2024, 6),
+                part_1: Some(300),
+                part_2: Some(1500),
+            }
+        );
+    }
+
+    #[test]
+    fn counts_from_unlock_without_scaffold() {
+        let solve_times = SolveTimes::default();
+        let stats = solve_times.day_stats(puzzle_id!(2024, 6), &get_mock_submissions());
+        assert_eq!((stats.part_1, stats.part_2), (Some(600), Some(1800)));
+
+        let stats = solve_times.day_stats(puzzle_id!(2024, 7), &get_mock_submissions());
+        assert_eq!((stats.part_1, stats.part_2), (None, None));
+    }
+
+    #[test]
+    fn round_trips_json() {
+        let mut solve_times = SolveTimes::default();
+        solve_times.record_start(day!(6), UNLOCK);
+
+        let json = tinyjson::JsonValue::from(solve_times).stringify().unwrap();
+        let solve_times = SolveTimes::try_from(json).unwrap();
+        assert_eq!(solve_times.start(day!(6)), Some(UNLOCK));
+    }
+}
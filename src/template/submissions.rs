@@ -0,0 +1,382 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{paths, timestamp, Day, Part, Verdict, Year};
+
+const SUBMISSIONS_FILE_NAME: &str = "submissions.json";
+
+/// A single answer that was sent to the website.
+#[derive(Clone, Debug)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub day: Day,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Reasons to refuse an answer before it is submitted.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Rejection {
+    KnownWrong,
+    AboveTooHigh(String),
+    BelowTooLow(String),
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::KnownWrong => write!(f, "this answer was already submitted and is wrong."),
+            Rejection::AboveTooHigh(bound) => {
+                write!(f, "a previous answer of {bound} was too high.")
+            }
+            Rejection::BelowTooLow(bound) => {
+                write!(f, "a previous answer of {bound} was too low.")
+            }
+        }
+    }
+}
+
+/// Ledger of all submitted answers of a year.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Submissions {
+    pub data: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Dehydrate submissions to the JSON file of a year.
+    pub fn store_file(&self, year: Year) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let path = paths::year_file(year, SUBMISSIONS_FILE_NAME);
+        paths::create_parent_dir(&path)?;
+        let mut file = fs::File::create(path)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate submissions from the JSON file of a year. If not present, returns an empty ledger.
+    /// A ledger that can not be read is an error, storing over it would lose the recorded submissions.
+    pub fn read_from_file(year: Year) -> Result<Self, String> {
+        let path = paths::year_file(year, SUBMISSIONS_FILE_NAME);
+
+        match fs::read_to_string(&path) {
+            Ok(json) => Submissions::try_from(json),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+        .map_err(|e| format!("could not read \"{path}\": {e}"))
+    }
+
+    /// Like [`Submissions::read_from_file`], but reports a ledger that can not be read and returns an empty one.
+    /// Only for reading, the returned ledger must not be stored.
+    pub fn read_or_warn(year: Year) -> Self {
+        Self::read_from_file(year).unwrap_or_else(|e| {
+            eprintln!("Ignoring recorded submissions, {e}");
+            Self::default()
+        })
+    }
+
+    /// Append a submission to the ledger, timestamped with the current time.
+    pub fn record(&mut self, day: Day, part: Part, answer: &str, verdict: Verdict) {
+        self.data.push(Submission {
+            timestamp: timestamp::now(),
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+
+    /// Whether a correct answer was recorded for the part.
+    pub fn is_solved(&self, day: Day, part: Part) -> bool {
+        self.data
+            .iter()
+            .any(|s| s.day == day && s.part == part && s.verdict == Verdict::Correct)
+    }
+
+    /// The answer that was recorded as correct for the part.
+    pub fn correct_answer(&self, day: Day, part: Part) -> Option<&str> {
+        self.data
+            .iter()
+            .find(|s| s.day == day && s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.answer.as_str())
+    }
+
+    /// Time of the first correct submission for the part.
+    pub fn solved_at(&self, day: Day, part: Part) -> Option<u64> {
+        self.data
+            .iter()
+            .filter(|s| s.day == day && s.part == part && s.verdict == Verdict::Correct)
+            .map(|s| s.timestamp)
+            .min()
+    }
+
+    /// Check an answer against previous submissions for the same part.
+    /// Numeric answers are additionally checked against known "too high" and "too low" bounds.
+    pub fn check(&self, day: Day, part: Part, answer: &str) -> Result<(), Rejection> {
+        let previous: Vec<&Submission> = self
+            .data
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect();
+
+        if previous
+            .iter()
+            .any(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(Rejection::KnownWrong);
+        }
+
+        let Ok(value) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+
+        let bound = |verdict: Verdict| {
+            previous
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+
+        if let Some(upper) = bound(Verdict::TooHigh).min() {
+            if value >= upper {
+                return Err(Rejection::AboveTooHigh(upper.to_string()));
+            }
+        }
+
+        if let Some(lower) = bound(Verdict::TooLow).max() {
+            if value <= lower {
+                return Err(Rejection::BelowTooLow(lower.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Submissions> for JsonValue {
+    fn from(value: Submissions) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Submissions {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Submissions {
+            data: json_data
+                .iter()
+                .map(Submission::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Submission> for JsonValue {
+    fn from(value: &Submission) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        #[allow(clippy::cast_precision_loss)]
+        map.insert(
+            "timestamp".into(),
+            JsonValue::Number(value.timestamp as f64),
+        );
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "part".into(),
+            JsonValue::Number(f64::from(value.part.into_inner())),
+        );
+        map.insert("answer".into(), JsonValue::String(value.answer.clone()));
+        map.insert(
+            "verdict".into(),
+            JsonValue::String(value.verdict.key().to_string()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Submission {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected submission to be a JSON object.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let timestamp = json
+            .get("timestamp")
+            .and_then(|v| v.get::<f64>().copied())
+            .map(|v| v as u64)
+            .ok_or("Expected submission.timestamp to be a number.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .ok_or("Expected submission.day to be a Day struct.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .and_then(|v| Part::new(v as u8))
+            .ok_or("Expected submission.part to be a Part struct.")?;
+
+        let answer = json
+            .get("answer")
+            .and_then(|v| v.get::<String>())
+            .ok_or("Expected submission.answer to be a string.")?;
+
+        let verdict = json
+            .get("verdict")
+            .and_then(|v| v.get::<String>())
+            .and_then(|v| Verdict::from_str(v).ok())
+            .ok_or("Expected submission.verdict to be a verdict.")?;
+
+        Ok(Submission {
+            timestamp,
+            day,
+            part,
+            answer: answer.clone(),
+            verdict,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use crate::template::Part;
+    use crate::{day, part};
+
+    use super::{Rejection, Submission, Submissions, Verdict};
+
+    fn submission(part: Part, answer: &str, verdict: Verdict) -> Submission {
+        Submission {
+            timestamp: 1_733_461_200,
+            day: day!(6),
+            part,
+            answer: answer.into(),
+            verdict,
+        }
+    }
+
+    fn get_mock_submissions() -> Submissions {
+        Submissions {
+            data: vec![
+                submission(part!(1), "500", Verdict::TooHigh),
+                submission(part!(1), "100", Verdict::TooLow),
+                submission(part!(1), "300", Verdict::Wrong),
+                submission(part!(2), "abc", Verdict::Wrong),
+            ],
+        }
+    }
+
+    mod check {
+        use super::{get_mock_submissions, Rejection};
+        use crate::{day, part};
+
+        #[test]
+        fn refuses_known_wrong_answers() {
+            let submissions = get_mock_submissions();
+            assert_eq!(
+                submissions.check(day!(6), part!(1), "300"),
+                Err(Rejection::KnownWrong)
+            );
+            assert_eq!(
+                submissions.check(day!(6), part!(2), "abc"),
+                Err(Rejection::KnownWrong)
+            );
+        }
+
+        #[test]
+        fn refuses_answers_outside_bounds() {
+            let submissions = get_mock_submissions();
+            assert_eq!(
+                submissions.check(day!(6), part!(1), "501"),
+                Err(Rejection::AboveTooHigh("500".into()))
+            );
+            assert_eq!(
+                submissions.check(day!(6), part!(1), "99"),
+                Err(Rejection::BelowTooLow("100".into()))
+            );
+        }
+
+        #[test]
+        fn accepts_answers_within_bounds() {
+            let submissions = get_mock_submissions();
+            assert_eq!(submissions.check(day!(6), part!(1), "250"), Ok(()));
+            assert_eq!(submissions.check(day!(6), part!(2), "999"), Ok(()));
+            assert_eq!(submissions.check(day!(7), part!(1), "300"), Ok(()));
+        }
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let json = tinyjson::JsonValue::from(get_mock_submissions())
+            .stringify()
+            .unwrap();
+        let submissions = Submissions::try_from(json).unwrap();
+        assert_eq!(submissions.data.len(), 4);
+        let first = submissions.data.first().unwrap();
+        assert_eq!(first.timestamp, 1_733_461_200);
+        assert_eq!(first.day, day!(6));
+        assert_eq!(first.part, part!(1));
+        assert_eq!(first.answer, "500");
+        assert_eq!(first.verdict, Verdict::TooHigh);
+    }
+
+    #[test]
+    fn detects_solved_parts() {
+        let mut submissions = get_mock_submissions();
+        assert!(!submissions.is_solved(day!(6), part!(1)));
+        submissions
+            .data
+            .push(submission(part!(1), "400", Verdict::Correct));
+        assert!(submissions.is_solved(day!(6), part!(1)));
+        assert!(!submissions.is_solved(day!(6), part!(2)));
+        assert_eq!(submissions.correct_answer(day!(6), part!(1)), Some("400"));
+        assert_eq!(submissions.correct_answer(day!(6), part!(2)), None);
+    }
+
+    #[test]
+    fn records_submissions() {
+        let mut submissions = Submissions::default();
+        submissions.record(day!(6), part!(1), "41", Verdict::Correct);
+        assert_eq!(submissions.data.len(), 1);
+        assert!(submissions.data[0].timestamp > 0);
+        assert_eq!(submissions.check(day!(6), part!(1), "41"), Ok(()));
+    }
+}
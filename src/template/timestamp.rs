@@ -0,0 +1,9 @@
+/// Timestamps of recorded events, e.g. submissions, scaffolded days and backups.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Returns the current time as seconds since the unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
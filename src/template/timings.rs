@@ -1,20 +1,20 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::{paths, Day, Part, PuzzleId, Year};
 
-static TIMINGS_FILE_PATH: &str = "./data/timings.json";
+const TIMINGS_FILE_NAME: &str = "timings.json";
 
-/// Represents benchmark times for a single day.
+/// Represents benchmark times for a single puzzle.
 #[derive(Clone, Debug)]
 pub struct Timing {
-    pub day: Day,
+    pub puzzle: PuzzleId,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
 }
 
-/// Represents benchmark times for a set of days.
+/// Represents benchmark times for a set of puzzles, possibly of several years.
 /// Can be serialized from / to JSON.
 #[derive(Clone, Debug, Default)]
 pub struct Timings {
@@ -25,13 +25,15 @@ impl Timings {
     /// Dehydrate timings to a JSON file.
     pub fn store_file(&self) -> Result<(), Error> {
         let json = JsonValue::from(self.clone());
-        let mut file = fs::File::create(TIMINGS_FILE_PATH)?;
+        let path = paths::shared_file(TIMINGS_FILE_NAME);
+        paths::create_parent_dir(&path)?;
+        let mut file = fs::File::create(path)?;
         json.format_to(&mut file)
     }
 
     /// Rehydrate timings from a JSON file. If not present, returns empty timings.
     pub fn read_from_file() -> Self {
-        fs::read_to_string(TIMINGS_FILE_PATH)
+        fs::read_to_string(paths::shared_file(TIMINGS_FILE_NAME))
             .map_err(|x| x.to_string())
             .and_then(Timings::try_from)
             .unwrap_or_default()
@@ -46,12 +48,12 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle == timing.puzzle) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by(|a, b| a.puzzle.cmp(&b.puzzle));
         Timings { data }
     }
 
@@ -60,10 +62,33 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+    /// Whether all parts of a puzzle are timed. Days with a single part only need part one.
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
+        self.data.iter().any(|t| {
+            t.puzzle == puzzle
+                && t.part_1.is_some()
+                && (t.part_2.is_some() || !puzzle.has_part(Part::Two))
+        })
+    }
+
+    /// Years with timings, in ascending order.
+    pub fn years(&self) -> Vec<Year> {
+        let mut years: Vec<Year> = self.data.iter().map(|t| t.puzzle.year).collect();
+        years.sort_unstable();
+        years.dedup();
+        years
+    }
+
+    /// Timings of the days of a single year's event.
+    pub fn for_year(&self, year: Year) -> Self {
+        Timings {
+            data: self
+                .data
+                .iter()
+                .filter(|t| t.puzzle.year == year && t.puzzle.is_in_event())
+                .cloned()
+                .collect(),
+        }
     }
 }
 
@@ -111,7 +136,14 @@ impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
-        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert(
+            "year".into(),
+            JsonValue::String(value.puzzle.year.to_string()),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::String(value.puzzle.day.to_string()),
+        );
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
         let part_1 = value.part_1.clone().map(JsonValue::String);
@@ -151,6 +183,15 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // timings stored before years were tracked belong to the configured year.
+        let year = match json.get("year") {
+            Some(v) => v
+                .get::<String>()
+                .and_then(|year| Year::from_str(year).ok())
+                .ok_or("Expected timing.year to be a Year struct.")?,
+            None => Year::configured(),
+        };
+
         let part_1 = json
             .get("part_1")
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
@@ -167,7 +208,7 @@ impl TryFrom<&JsonValue> for Timing {
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
         Ok(Timing {
-            day,
+            puzzle: PuzzleId::new(year, day),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
@@ -179,7 +220,7 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::puzzle_id;
 
     use super::{Timing, Timings};
 
@@ -187,19 +228,19 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
-                    day: day!(1),
+                    puzzle: puzzle_id!(2024, 1),
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
                 },
                 Timing {
-                    day: day!(2),
+                    puzzle: puzzle_id!(2024, 2),
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
                 },
                 Timing {
-                    day: day!(4),
+                    puzzle: puzzle_id!(2024, 4),
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
@@ -209,20 +250,61 @@ mod tests {
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::{day, puzzle_id, template::timings::Timings, year};
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2024", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
-            assert_eq!(timing.day, day!(1));
+            assert_eq!(timing.puzzle, puzzle_id!(2024, 1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_timings_without_year() {
+            let json = r#"{ "data": [{ "day": "03", "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].puzzle.day, day!(3));
+        }
+
+        #[test]
+        fn handles_timings_of_several_years() {
+            let json = r#"{ "data": [
+                { "year": "2023", "day": "25", "part_1": "1ms", "part_2": null, "total_nanos": 1 },
+                { "year": "2024", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1 }
+            ] }"#
+                .to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data[0].puzzle, puzzle_id!(2023, 25));
+            assert_eq!(timings.data[1].puzzle, puzzle_id!(2024, 1));
+            assert_eq!(timings.years(), vec![year!(2023), year!(2024)]);
+            assert_eq!(timings.for_year(year!(2023)).data.len(), 1);
+        }
+
+        #[test]
+        fn skips_days_beyond_the_event() {
+            let json = r#"{ "data": [
+                { "year": "2025", "day": "12", "part_1": "1ms", "part_2": null, "total_nanos": 1 },
+                { "year": "2025", "day": "13", "part_1": "1ms", "part_2": null, "total_nanos": 1 }
+            ] }"#
+                .to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let year_timings = timings.for_year(year!(2025));
+            assert_eq!(year_timings.data.len(), 1);
+            assert_eq!(year_timings.data[0].puzzle, puzzle_id!(2025, 12));
+        }
+
+        #[test]
+        #[should_panic]
+        fn panics_for_invalid_year() {
+            let json = r#"{ "data": [{ "year": "1999", "day": "01", "part_1": null, "part_2": null, "total_nanos": 0 }] }"#.to_string();
+            Timings::try_from(json).unwrap();
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -270,7 +352,7 @@ mod tests {
 
     mod is_day_complete {
         use crate::{
-            day,
+            puzzle_id,
             template::timings::{Timing, Timings},
         };
 
@@ -278,48 +360,62 @@ mod tests {
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle_id!(2024, 1),
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(puzzle_id!(2024, 1)), true);
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle_id!(2024, 1),
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    total_nanos: 1_000_000_000_f64,
+                }],
+            };
+
+            assert_eq!(timings.is_day_complete(puzzle_id!(2024, 1)), false);
+        }
+
+        #[test]
+        fn handles_single_part_days() {
+            let timings = Timings {
+                data: vec![Timing {
+                    puzzle: puzzle_id!(2024, 25),
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle_id!(2024, 25)), true);
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
-                    day: day!(1),
+                    puzzle: puzzle_id!(2024, 1),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(puzzle_id!(2024, 1)), false);
         }
     }
 
     mod merge {
         use crate::{
-            day,
+            puzzle_id,
             template::timings::{Timing, Timings},
         };
 
@@ -330,7 +426,7 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(3),
+                    puzzle: puzzle_id!(2024, 3),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -338,10 +434,10 @@ mod tests {
             };
             let merged = timings.merge(&other);
             assert_eq!(merged.data.len(), 4);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
-            assert_eq!(merged.data[2].day, day!(3));
-            assert_eq!(merged.data[3].day, day!(4));
+            assert_eq!(merged.data[0].puzzle, puzzle_id!(2024, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle_id!(2024, 2));
+            assert_eq!(merged.data[2].puzzle, puzzle_id!(2024, 3));
+            assert_eq!(merged.data[3].puzzle, puzzle_id!(2024, 4));
         }
 
         #[test]
@@ -350,7 +446,7 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
-                    day: day!(2),
+                    puzzle: puzzle_id!(2024, 2),
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
@@ -359,10 +455,10 @@ mod tests {
             let merged = timings.merge(&other);
 
             assert_eq!(merged.data.len(), 3);
-            assert_eq!(merged.data[0].day, day!(1));
-            assert_eq!(merged.data[1].day, day!(2));
+            assert_eq!(merged.data[0].puzzle, puzzle_id!(2024, 1));
+            assert_eq!(merged.data[1].puzzle, puzzle_id!(2024, 2));
             assert_eq!(merged.data[1].total_nanos, 0_f64);
-            assert_eq!(merged.data[2].day, day!(4));
+            assert_eq!(merged.data[2].puzzle, puzzle_id!(2024, 4));
         }
 
         #[test]
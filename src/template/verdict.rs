@@ -0,0 +1,200 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// The outcome of submitting an answer, parsed from the response of the website.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    AlreadySolved,
+    /// An answer was submitted too recently, another one can be submitted after waiting for the duration.
+    RateLimited(Duration),
+    WrongLevel,
+}
+
+impl Verdict {
+    /// Parses the response message of a submission, returns [`None`] if the message is not recognized.
+    /// The message may be plain text or html, line breaks and repeated spaces are ignored.
+    pub fn from_message(message: &str) -> Option<Self> {
+        let message = message.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if message.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if message.contains("That's not the right answer") {
+            if message.contains("your answer is too high") {
+                Some(Verdict::TooHigh)
+            } else if message.contains("your answer is too low") {
+                Some(Verdict::TooLow)
+            } else {
+                Some(Verdict::Wrong)
+            }
+        } else if message.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(
+                parse_wait_duration(&message).unwrap_or_default(),
+            ))
+        } else if message.contains("You don't seem to be solving the right level") {
+            Some(Verdict::WrongLevel)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the verdict marks the answer as incorrect.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+
+    /// Whether the submission is recorded in the ledger.
+    /// The website does not check an answer that is rate limited, so it might still be correct.
+    pub fn is_recorded(self) -> bool {
+        !matches!(self, Verdict::RateLimited(_))
+    }
+
+    /// Identifier of the verdict used when storing it.
+    pub fn key(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+            Verdict::AlreadySolved => "already_solved",
+            Verdict::RateLimited(_) => "rate_limited",
+            Verdict::WrongLevel => "wrong_level",
+        }
+    }
+}
+
+/// Parses durations like `42s`, `1m 5s` or `1h 2m 3s` from a message like
+/// "You have 1m 5s left to wait".
+fn parse_wait_duration(message: &str) -> Option<Duration> {
+    let (before, _) = message.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("have ")?;
+
+    wait.split_whitespace()
+        .try_fold(Duration::ZERO, |acc, token| {
+            let unit = token.chars().last()?;
+            let value: u64 = token[..token.len() - 1].parse().ok()?;
+            let secs = match unit {
+                'h' => value * 3600,
+                'm' => value * 60,
+                's' => value,
+                _ => return None,
+            };
+            Some(acc + Duration::from_secs(secs))
+        })
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "⭐ That's the right answer!"),
+            Verdict::TooHigh => write!(f, "✖ That's not the right answer, it is too high."),
+            Verdict::TooLow => write!(f, "✖ That's not the right answer, it is too low."),
+            Verdict::Wrong => write!(f, "✖ That's not the right answer."),
+            Verdict::AlreadySolved => write!(f, "⭐ This part is already solved."),
+            Verdict::RateLimited(wait) => write!(
+                f,
+                "⏳ An answer was submitted too recently, {}s left to wait.",
+                wait.as_secs()
+            ),
+            Verdict::WrongLevel => write!(
+                f,
+                "✖ This part can not be submitted, is the previous part solved?"
+            ),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    /// Parses the identifier returned by [`Verdict::key`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            "rate_limited" => Ok(Verdict::RateLimited(Duration::ZERO)),
+            "wrong_level" => Ok(Verdict::WrongLevel),
+            _ => Err(format!("unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::Verdict;
+
+    // responses as printed by aoc-cli, wrapped to the terminal width.
+    const CLI_CORRECT: &str = "\nThat's the right answer! You are one gold star closer to finding the Chief\nHistorian. [Continue to Part Two]\n";
+    const CLI_TOO_HIGH: &str = "\nThat's not the right answer; your answer is too high. If you're stuck, make\nsure you're using the full input data; there are also some general tips on\nthe about page, or you can ask for hints on the subreddit. Please wait one\nminute before trying again. [Return to Day 6]\n";
+    const CLI_TOO_LOW: &str = "\nThat's not the right answer; your answer is too\nlow. If you're stuck, make sure you're using the full input data. Please wait\none minute before trying again. [Return to Day 6]\n";
+    const CLI_WRONG: &str = "\nThat's not the right answer. If you're stuck, make sure you're using the full\ninput data. Please wait one minute before trying again. [Return to Day 6]\n";
+    const CLI_RATE_LIMITED: &str = "\nYou gave an answer too recently; you have to wait after submitting an answer\nbefore trying again. You have 42s left to wait. [Return to Day 6]\n";
+    const CLI_WRONG_LEVEL: &str = "\nYou don't seem to be solving the right level. Did you already complete it?\n[Return to Day 6]\n";
+
+    // responses as returned by the built-in client.
+    const HTML_CORRECT: &str = "<article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian. <a href=\"/2024/day/6#part2\">[Continue to Part Two]</a></p></article>";
+    const HTML_RATE_LIMITED: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 31s left to wait. <a href=\"/2024/day/6\">[Return to Day 6]</a></p></article>";
+
+    #[test]
+    fn parses_cli_responses() {
+        assert_eq!(Verdict::from_message(CLI_CORRECT), Some(Verdict::Correct));
+        assert_eq!(Verdict::from_message(CLI_TOO_HIGH), Some(Verdict::TooHigh));
+        assert_eq!(Verdict::from_message(CLI_TOO_LOW), Some(Verdict::TooLow));
+        assert_eq!(Verdict::from_message(CLI_WRONG), Some(Verdict::Wrong));
+        assert_eq!(
+            Verdict::from_message(CLI_RATE_LIMITED),
+            Some(Verdict::RateLimited(Duration::from_secs(42)))
+        );
+        assert_eq!(
+            Verdict::from_message(CLI_WRONG_LEVEL),
+            Some(Verdict::WrongLevel)
+        );
+    }
+
+    #[test]
+    fn parses_html_responses() {
+        assert_eq!(Verdict::from_message(HTML_CORRECT), Some(Verdict::Correct));
+        assert_eq!(
+            Verdict::from_message(HTML_RATE_LIMITED),
+            Some(Verdict::RateLimited(Duration::from_secs(271)))
+        );
+    }
+
+    #[test]
+    fn handles_unknown_responses() {
+        assert_eq!(Verdict::from_message(""), None);
+        assert_eq!(Verdict::from_message("Error: HTTP status 500"), None);
+    }
+
+    #[test]
+    fn records_checked_answers_only() {
+        assert!(Verdict::Correct.is_recorded());
+        assert!(Verdict::TooHigh.is_recorded());
+        assert!(!Verdict::RateLimited(Duration::from_secs(42)).is_recorded());
+    }
+
+    #[test]
+    fn roundtrips_keys() {
+        for verdict in [
+            Verdict::Correct,
+            Verdict::TooHigh,
+            Verdict::TooLow,
+            Verdict::Wrong,
+            Verdict::AlreadySolved,
+            Verdict::WrongLevel,
+        ] {
+            assert_eq!(verdict.key().parse::<Verdict>(), Ok(verdict));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+/// Detects changes of files by polling them, for `cargo solve --watch`.
+///
+/// Watched paths can be files or folders, folders are watched recursively.
+/// Paths that do not exist yet are watched as well, so creating them counts as a change.
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+type Snapshot = HashMap<PathBuf, (Option<SystemTime>, u64)>;
+
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    snapshot: Snapshot,
+}
+
+fn collect(path: &Path, snapshot: &mut Snapshot) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            collect(&entry.path(), snapshot);
+        }
+    } else {
+        snapshot.insert(
+            path.to_path_buf(),
+            (metadata.modified().ok(), metadata.len()),
+        );
+    }
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = impl Into<PathBuf>>) -> Self {
+        let mut watcher = Self {
+            paths: paths.into_iter().map(Into::into).collect(),
+            snapshot: Snapshot::new(),
+        };
+        watcher.snapshot = watcher.take_snapshot();
+        watcher
+    }
+
+    fn take_snapshot(&self) -> Snapshot {
+        let mut snapshot = Snapshot::new();
+        for path in &self.paths {
+            collect(path, &mut snapshot);
+        }
+        snapshot
+    }
+
+    /// Files that were created, modified or removed since the last call, in sorted order.
+    pub fn changes(&mut self) -> Vec<PathBuf> {
+        let snapshot = self.take_snapshot();
+
+        let mut changes: Vec<PathBuf> = snapshot
+            .iter()
+            .filter(|(path, state)| self.snapshot.get(*path) != Some(state))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.snapshot
+                    .keys()
+                    .filter(|path| !snapshot.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+
+        changes.sort_unstable();
+        self.snapshot = snapshot;
+        changes
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::{env, fs, process};
+
+    use super::Watcher;
+
+    #[test]
+    fn detects_created_modified_and_removed_files() {
+        let root = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        let data = root.join("data");
+        let input = data.join("01.txt");
+        let missing = root.join("lib.rs");
+        fs::create_dir_all(&data).unwrap();
+        fs::write(&input, "1").unwrap();
+
+        let mut watcher = Watcher::new([&data, &missing]);
+        assert!(watcher.changes().is_empty());
+
+        // the size changes as well, the modification time might not on coarse file systems.
+        fs::write(&input, "1 2").unwrap();
+        assert_eq!(watcher.changes(), vec![input.clone()]);
+        assert!(watcher.changes().is_empty());
+
+        fs::write(&missing, "").unwrap();
+        fs::remove_file(&input).unwrap();
+        assert_eq!(watcher.changes(), vec![input, missing]);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,197 @@
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+#[cfg(not(feature = "today"))]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::template::{server_year, Day};
+
+/// The year of the first advent of code event.
+const FIRST_YEAR: u16 = 2015;
+
+/// Events up to 2024 have 25 days, later ones have 12.
+const LONG_EVENT_LENGTH: u8 = 25;
+const SHORT_EVENT_LENGTH: u8 = 12;
+const FIRST_SHORT_EVENT: u16 = 2025;
+
+/// A valid year of advent of code (i.e. 2015 or later).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2024).unwrap();
+/// assert_eq!(year.to_string(), "2024")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's a year with an event,
+    /// returns [`None`] otherwise.
+    pub fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(year: u16) -> Self {
+        Self(year)
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Returns the year set in `AOC_YEAR`, if it is set and valid.
+    pub fn from_env() -> Option<Self> {
+        env::var("AOC_YEAR").ok()?.trim().parse().ok()
+    }
+
+    /// Returns the year of the latest event that has started.
+    /// Before the 1st of december, this is the previous year.
+    /// With the `today` feature, `AOC_NOW` overrides the current time.
+    pub fn current() -> Self {
+        #[cfg(feature = "today")]
+        let now = crate::template::clock::now().timestamp();
+
+        #[cfg(not(feature = "today"))]
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| i64::try_from(d.as_secs()).unwrap_or(i64::MAX));
+
+        Self::at(now)
+    }
+
+    /// Returns the year of the latest event that has started at a time, given as seconds since the unix epoch.
+    fn at(timestamp: i64) -> Self {
+        let year = server_year(timestamp);
+        let year = if Day::__new_unchecked(1).unlock_timestamp(year) > timestamp {
+            year - 1
+        } else {
+            year
+        };
+
+        Self(u16::try_from(year).unwrap_or(FIRST_YEAR).max(FIRST_YEAR))
+    }
+
+    /// Returns the year set in `AOC_YEAR`, falling back to the [current](Year::current) year.
+    pub fn configured() -> Self {
+        Self::from_env().unwrap_or_else(Self::current)
+    }
+
+    /// Returns the number of puzzle days of this year's event.
+    /// `AOC_EVENT_DAYS` overrides the length of the event of the year set in `AOC_YEAR`.
+    pub fn event_length(self) -> u8 {
+        let configured = env::var("AOC_EVENT_DAYS")
+            .ok()
+            .and_then(|x| x.trim().parse::<u8>().ok())
+            .filter(|days| (1..=LONG_EVENT_LENGTH).contains(days));
+
+        match configured {
+            Some(days) if Self::from_env() == Some(self) => days,
+            _ if self.0 >= FIRST_SHORT_EVENT => SHORT_EVENT_LENGTH,
+            _ => LONG_EVENT_LENGTH,
+        }
+    }
+
+    /// Returns the last day of this year's event. Its puzzle only has a single part.
+    pub fn last_day(self) -> Day {
+        Day::__new_unchecked(self.event_length())
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {{
+        const _ASSERT: () = assert!(
+            $year >= 2015,
+            concat!("invalid year `", $year, "`, expecting 2015 or later"),
+        );
+        $crate::template::Year::__new_unchecked($year)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+
+    use super::Year;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!(Year::from_str("2024").ok(), Some(Year(2024)));
+        assert_eq!(Year::from_str("2015").ok(), Some(Year(2015)));
+        assert!(Year::from_str("2014").is_err());
+        assert!(Year::from_str("24").is_err());
+        assert!(Year::from_str("next").is_err());
+    }
+
+    #[test]
+    fn event_lengths() {
+        assert_eq!(Year(2015).event_length(), 25);
+        assert_eq!(Year(2024).event_length(), 25);
+        assert_eq!(Year(2025).event_length(), 12);
+        assert_eq!(Year(2025).last_day(), crate::day!(12));
+    }
+
+    #[test]
+    fn year_starts_on_the_first_of_december() {
+        // 2024-12-01T04:59:59Z and 2024-12-01T05:00:00Z
+        assert_eq!(Year::at(1_733_029_199), Year(2023));
+        assert_eq!(Year::at(1_733_029_200), Year(2024));
+        // 2025-01-01T12:00:00Z
+        assert_eq!(Year::at(1_735_732_800), Year(2024));
+        assert_eq!(Year::at(0), Year(2015));
+    }
+
+    #[test]
+    fn current_year_has_started() {
+        let year = Year::current();
+        assert!(year >= Year(2024));
+        assert!(
+            crate::day!(1).unlock_timestamp(i64::from(year.into_inner()))
+                <= crate::template::timestamp::now() as i64
+        );
+    }
+}
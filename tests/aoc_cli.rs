@@ -0,0 +1,214 @@
+//! Runs the command handlers against a fake `aoc` executable.
+//! The handlers exit the process on errors, so they are tested by running the compiled binaries.
+#![cfg(all(feature = "test_lib", unix, not(feature = "client")))]
+
+use std::fs;
+use std::process::{Command, Output};
+
+use advent_of_code::template::fake_aoc::FakeAoc;
+
+const NOT_INSTALLED: &str = "Try running \"cargo install aoc-cli\" to install it.";
+
+/// Runs a binary for the puzzles of 2024, unless the test configures another year.
+fn run_in_year(year: &str, bin: &str, args: &[&str]) -> Output {
+    Command::new(bin)
+        .args(args)
+        .env("AOC_YEAR", year)
+        .output()
+        .unwrap()
+}
+
+fn run(bin: &str, args: &[&str]) -> Output {
+    run_in_year("2024", bin, args)
+}
+
+fn run_cli(args: &[&str]) -> Output {
+    run(env!("CARGO_BIN_EXE_advent_of_code"), args)
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+/// Names and contents of the solutions in the project's `src/bin`.
+fn project_solutions() -> Vec<(String, String)> {
+    let mut solutions: Vec<(String, String)> = fs::read_dir("src/bin")
+        .unwrap()
+        .flatten()
+        .map(|entry| {
+            (
+                entry.file_name().to_string_lossy().into_owned(),
+                fs::read_to_string(entry.path()).unwrap_or_default(),
+            )
+        })
+        .collect();
+    solutions.sort();
+    solutions
+}
+
+#[test]
+fn download_reports_missing_aoc_cli() {
+    let _fake = FakeAoc::not_installed();
+    let output = run_cli(&["download", "1"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains(NOT_INSTALLED));
+}
+
+#[test]
+fn read_reports_missing_aoc_cli() {
+    let _fake = FakeAoc::not_installed();
+    let output = run_cli(&["read", "1"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains(NOT_INSTALLED));
+}
+
+#[test]
+fn submit_reports_missing_aoc_cli() {
+    let fake = FakeAoc::not_installed();
+    fake.write_data_file("2024/inputs/01.txt", "3   4\n4   3\n");
+    let output = run(env!("CARGO_BIN_EXE_2024-01"), &["--submit", "1"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains(NOT_INSTALLED));
+}
+
+#[test]
+fn downloads_input_and_puzzle() {
+    let fake = FakeAoc::install(r#"printf '3   4\n4   3\n' > "$3"; printf 'The puzzle.\n' > "$5""#);
+    let solution = fake.solutions_dir().join("2024-01.rs");
+    fs::write(&solution, "fn main() {}\n").unwrap();
+    let project = project_solutions();
+    let output = run_cli(&["download", "1"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Successfully wrote input"));
+    assert_eq!(fake.calls().len(), 2);
+    assert_eq!(fake.calls()[0], vec!["-V"]);
+    assert_eq!(fake.calls()[1].last().map(String::as_str), Some("download"));
+    assert_eq!(
+        fake.read_data_file("2024/inputs/01.txt").as_deref(),
+        Some("3   4\n4   3\n")
+    );
+    assert!(fs::read_to_string(solution)
+        .unwrap()
+        .starts_with("//! # Day 1\n"));
+    assert_eq!(project_solutions(), project);
+}
+
+#[test]
+fn downloads_puzzle_of_configured_year() {
+    let fake = FakeAoc::install(r#"printf '3   4\n' > "$3"; printf 'The puzzle.\n' > "$5""#);
+    let output = run_in_year(
+        "2019",
+        env!("CARGO_BIN_EXE_advent_of_code"),
+        &["download", "12"],
+    );
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(
+        fake.calls()[1][5..],
+        ["--year", "2019", "--day", "12", "download"]
+    );
+    assert_eq!(
+        fake.read_data_file("2019/inputs/12.txt").as_deref(),
+        Some("3   4\n")
+    );
+}
+
+#[test]
+fn download_reports_bad_exit_status() {
+    let fake = FakeAoc::install(r#"case "$1" in -V) ;; *) exit 1;; esac"#);
+    let output = run_cli(&["download", "1"]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("aoc-cli exited with a non-zero status."));
+    assert_eq!(fake.read_data_file("2024/inputs/01.txt"), None);
+}
+
+#[test]
+fn read_prints_downloaded_puzzle_without_aoc_cli() {
+    let fake = FakeAoc::not_installed();
+    fake.write_data_file("2024/puzzles/01.md", "The puzzle.\n");
+    let output = run_cli(&["read", "1"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output).trim(), "The puzzle.");
+}
+
+#[test]
+fn submits_answer_and_records_it() {
+    let fake = FakeAoc::install(
+        r#"case "$1" in -V) ;; *) echo "That's the right answer!  You are one gold star closer.";; esac"#,
+    );
+    fake.write_data_file(
+        "2024/inputs/01.txt",
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    );
+    let output = run(env!("CARGO_BIN_EXE_2024-01"), &["--submit", "1"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("Solved part 1 in"));
+    assert_eq!(
+        fake.last_call(),
+        vec!["--year", "2024", "--day", "01", "submit", "1", "11"]
+    );
+    assert!(fake
+        .read_data_file("2024/submissions.json")
+        .is_some_and(|x| x.contains("\"11\"")));
+}
+
+#[test]
+fn does_not_record_rate_limited_submissions() {
+    let fake = FakeAoc::install(
+        r#"case "$1" in -V) ;; *) echo "You gave an answer too recently.  You have 42s left to wait.";; esac"#,
+    );
+    fake.write_data_file(
+        "2024/inputs/01.txt",
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    );
+    let output = run(env!("CARGO_BIN_EXE_2024-01"), &["--submit", "1"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("42s left to wait"));
+    assert_eq!(fake.calls().len(), 2);
+    assert_eq!(fake.read_data_file("2024/submissions.json"), None);
+}
+
+#[test]
+fn refuses_to_submit_with_a_corrupt_ledger() {
+    let fake = FakeAoc::install(r#"echo "That's the right answer!""#);
+    fake.write_data_file(
+        "2024/inputs/01.txt",
+        "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+    );
+    fake.write_data_file("2024/submissions.json", "{\"data\": [");
+    let output = run(env!("CARGO_BIN_EXE_2024-01"), &["--submit", "1"]);
+
+    assert!(stderr(&output).contains("Refusing to submit 11, could not read"));
+    assert!(fake.calls().is_empty());
+    assert_eq!(
+        fake.read_data_file("2024/submissions.json").as_deref(),
+        Some("{\"data\": [")
+    );
+}
+
+#[test]
+fn keeps_unreadable_solve_times_when_scaffolding() {
+    let fake = FakeAoc::not_installed();
+    fake.write_data_file("2024/solve_times.json", "{\"data\": [");
+    let output = run_cli(&["scaffold", "7"]);
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stderr(&output).contains("Not recording the start time of 2024-07"));
+    assert!(fake.solutions_dir().join("2024-07.rs").exists());
+    assert_eq!(
+        fake.read_data_file("2024/solve_times.json").as_deref(),
+        Some("{\"data\": [")
+    );
+}